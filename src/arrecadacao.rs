@@ -0,0 +1,183 @@
+//! Boleto de arrecadação, usado por concessionárias de serviços públicos, prefeituras e órgãos do governo
+//! O código de barras possui 44 dígitos e sempre começa com 8; a linha digitável o divide em quatro blocos de 11 dígitos, cada um seguido do próprio dígito verificador
+//! O 3º dígito, identificador de valor, define se os dígitos verificadores usam o módulo 10 (6 e 7) ou o módulo 11 (8 e 9)
+//!```rust
+//! # use validador_br::formatar::Formatar;
+//! # use validador_br::types::{Arrecadacao, Segmento};
+//! # use validador_br::validador::Validador;
+//! let conta = Arrecadacao("83640000001-1 33120138000-2 81288462711-6 08013618155-1");
+//! assert!(conta.validar());
+//! assert_eq!(conta.segmento().unwrap(), Segmento::EnergiaGas);
+//! assert_eq!(conta.valor().unwrap(), 13312);
+//! assert_eq!(conta.empresa().unwrap(), "0138");
+//! assert_eq!(conta.codigo_barras().unwrap(), "83640000001331201380008128846271108013618155");
+//!
+//! let barras = Arrecadacao("83640000001331201380008128846271108013618155");
+//! assert_eq!(Arrecadacao(&barras.linha_digitavel().unwrap()).formatar(), conta.0);
+//!```
+
+use std::fmt;
+
+use crate::boleto::dv_modulo_10;
+use crate::erro::ErroValidacao;
+use crate::funcoes::{calc_digito, onze_menos_mod11, texto, verificar_caracteres, verificar_quantidade};
+use crate::types::{Arrecadacao, Segmento};
+use crate::validador::verificar_dv;
+
+/// Identifica o produto arrecadação no 1º dígito
+pub const PRODUTO_ARRECADACAO: u32 = 8;
+
+impl Segmento {
+    pub const TODOS: [Segmento; 8] = [
+        Segmento::Prefeituras,
+        Segmento::Saneamento,
+        Segmento::EnergiaGas,
+        Segmento::Telecomunicacoes,
+        Segmento::OrgaosGovernamentais,
+        Segmento::Carnes,
+        Segmento::MultasTransito,
+        Segmento::UsoBanco,
+    ];
+
+    /// 2º dígito do código de barras; os dígitos 0 e 8 não são usados
+    pub fn digito(&self) -> u8 {
+        match self {
+            Segmento::UsoBanco => 9,
+            segmento => *segmento as u8 + 1,
+        }
+    }
+
+    pub fn from_digito(digito: u8) -> Option<Segmento> {
+        Segmento::TODOS.into_iter().find(|segmento| segmento.digito() == digito)
+    }
+
+    pub fn nome(&self) -> &'static str {
+        match self {
+            Segmento::Prefeituras => "Prefeituras",
+            Segmento::Saneamento => "Saneamento",
+            Segmento::EnergiaGas => "Energia elétrica e gás",
+            Segmento::Telecomunicacoes => "Telecomunicações",
+            Segmento::OrgaosGovernamentais => "Órgãos governamentais",
+            Segmento::Carnes => "Carnês e assemelhados",
+            Segmento::MultasTransito => "Multas de trânsito",
+            Segmento::UsoBanco => "Uso exclusivo do banco",
+        }
+    }
+
+    /// O segmento 6 identifica a empresa pelos 8 primeiros dígitos do CNPJ; os demais usam o código de 4 dígitos atribuído pela FEBRABAN
+    fn tamanho_empresa(&self) -> usize {
+        match self {
+            Segmento::Carnes => 8,
+            _ => 4,
+        }
+    }
+}
+
+impl fmt::Display for Segmento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.nome())
+    }
+}
+
+/// Pesos de 2 a 9 da direita para a esquerda; os restos 0 e 1 resultam no dígito 0
+fn dv_modulo_11(digitos: &[u32]) -> u32 {
+    let multiplicadores = (0..digitos.len()).rev().map(|index| 2 + index as u32 % 8).collect();
+    calc_digito(digitos.to_vec(), multiplicadores, onze_menos_mod11)
+}
+
+/// Módulo indicado pelo identificador de valor, no 3º dígito
+fn calculo_dv(digitos: &[u32]) -> Result<fn(&[u32]) -> u32, ErroValidacao> {
+    match digitos[2] {
+        6 | 7 => Ok(dv_modulo_10),
+        8 | 9 => Ok(dv_modulo_11),
+        _ => Err(ErroValidacao::PrefixoInvalido {
+            encontrado: texto(&digitos[..3]),
+        }),
+    }
+}
+
+/// Produto 8 seguido de um segmento conhecido
+fn verificar_prefixo(digitos: &[u32]) -> Result<(), ErroValidacao> {
+    if digitos[0] == PRODUTO_ARRECADACAO && Segmento::from_digito(digitos[1] as u8).is_some() {
+        Ok(())
+    } else {
+        Err(ErroValidacao::PrefixoInvalido {
+            encontrado: texto(&digitos[..2]),
+        })
+    }
+}
+
+/// Segmento de um código de barras já validado
+fn segmento(barras: &[u32]) -> Segmento {
+    Segmento::from_digito(barras[1] as u8).expect("o segmento é verificado na validação")
+}
+
+impl Arrecadacao<'_> {
+    /// Valida a linha digitável ou o código de barras e retorna os 44 dígitos do código de barras
+    pub(crate) fn digitos_barras(numero: &str) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_caracteres(numero)?;
+        let digitos = verificar_quantidade(numero, 44, 48)?;
+        if !matches!(digitos.len(), 44 | 48) {
+            return Err(ErroValidacao::QuantidadeDigitos {
+                minimo: 44,
+                maximo: 48,
+                encontrado: digitos.len(),
+            });
+        }
+        verificar_prefixo(&digitos)?;
+        let calcular = calculo_dv(&digitos)?;
+        let barras = if digitos.len() == 48 {
+            let mut barras = Vec::with_capacity(44);
+            for bloco in digitos.chunks(12) {
+                verificar_dv(vec![calcular(&bloco[..11])], vec![bloco[11]])?;
+                barras.extend_from_slice(&bloco[..11]);
+            }
+            barras
+        } else {
+            digitos
+        };
+        let sem_dv = [&barras[..3], &barras[4..]].concat();
+        verificar_dv(vec![calcular(&sem_dv)], vec![barras[3]])?;
+        Ok(barras)
+    }
+
+    /// Os 44 dígitos do código de barras
+    pub fn codigo_barras(&self) -> Result<String, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| texto(&barras))
+    }
+
+    /// Os 48 dígitos da linha digitável, sem formatação; use `Formatar::formatar` para separar os blocos
+    pub fn linha_digitavel(&self) -> Result<String, ErroValidacao> {
+        let barras = Self::digitos_barras(self.0)?;
+        let calcular = calculo_dv(&barras)?;
+        Ok(barras
+            .chunks(11)
+            .flat_map(|bloco| [texto(bloco), calcular(bloco).to_string()])
+            .collect())
+    }
+
+    pub fn segmento(&self) -> Result<Segmento, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| segmento(&barras))
+    }
+
+    /// Indica se o valor está em reais, identificadores 6 e 8, ou em quantidade de moeda, identificadores 7 e 9
+    pub fn valor_efetivo(&self) -> Result<bool, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| matches!(barras[2], 6 | 8))
+    }
+
+    /// Valor em centavos, ou a quantidade de moeda quando `valor_efetivo()` é `false`
+    pub fn valor(&self) -> Result<u64, ErroValidacao> {
+        Self::digitos_barras(self.0)
+            .map(|barras| barras[4..15].iter().fold(0, |valor, &digito| valor * 10 + digito as u64))
+    }
+
+    /// Código da empresa ou órgão na FEBRABAN; no segmento `Carnes`, os 8 primeiros dígitos do CNPJ
+    pub fn empresa(&self) -> Result<String, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| texto(&barras[15..15 + segmento(&barras).tamanho_empresa()]))
+    }
+
+    /// Dígitos de uso livre da empresa, após a identificação da empresa
+    pub fn campo_livre(&self) -> Result<String, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| texto(&barras[15 + segmento(&barras).tamanho_empresa()..]))
+    }
+}
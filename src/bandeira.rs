@@ -0,0 +1,123 @@
+//! Identificação da bandeira do cartão de crédito pela faixa de IIN/BIN, os seis primeiros dígitos
+//! As faixas de Elo e Hipercard, emitidos no Brasil, são verificadas antes das demais, pois algumas se sobrepõem às de Visa, Diners e Discover
+//!```rust
+//! # use validador_br::types::{Bandeira, CartaoCredito};
+//! assert_eq!(CartaoCredito("5312 8338 4531 6765").bandeira(), Some(Bandeira::Mastercard));
+//! assert_eq!(CartaoCredito("6062 8271 2873 9719").bandeira(), Some(Bandeira::Hipercard));
+//! assert_eq!(Bandeira::Amex.tamanhos(), &[15]);
+//!```
+
+use std::fmt;
+
+use crate::funcoes::somente_digitos;
+use crate::owned::CartaoCreditoBuf;
+use crate::types::{Bandeira, CartaoCredito};
+
+/// Faixas de BIN com seis dígitos, da mais específica para a mais genérica; vale a primeira encontrada
+const FAIXAS: [(u32, u32, Bandeira); 46] = [
+    (401178, 401179, Bandeira::Elo),
+    (431274, 431274, Bandeira::Elo),
+    (438935, 438935, Bandeira::Elo),
+    (451416, 451416, Bandeira::Elo),
+    (457393, 457393, Bandeira::Elo),
+    (457631, 457632, Bandeira::Elo),
+    (504175, 504175, Bandeira::Elo),
+    (506699, 506778, Bandeira::Elo),
+    (509000, 509999, Bandeira::Elo),
+    (627780, 627780, Bandeira::Elo),
+    (636297, 636297, Bandeira::Elo),
+    (636368, 636368, Bandeira::Elo),
+    (650031, 650033, Bandeira::Elo),
+    (650035, 650051, Bandeira::Elo),
+    (650405, 650439, Bandeira::Elo),
+    (650485, 650538, Bandeira::Elo),
+    (650541, 650598, Bandeira::Elo),
+    (650700, 650718, Bandeira::Elo),
+    (650720, 650727, Bandeira::Elo),
+    (650901, 650978, Bandeira::Elo),
+    (651652, 651679, Bandeira::Elo),
+    (655000, 655019, Bandeira::Elo),
+    (655021, 655058, Bandeira::Elo),
+    (384100, 384100, Bandeira::Hipercard),
+    (384140, 384140, Bandeira::Hipercard),
+    (384160, 384160, Bandeira::Hipercard),
+    (606282, 606282, Bandeira::Hipercard),
+    (637095, 637095, Bandeira::Hipercard),
+    (637568, 637568, Bandeira::Hipercard),
+    (637599, 637599, Bandeira::Hipercard),
+    (637609, 637609, Bandeira::Hipercard),
+    (637612, 637612, Bandeira::Hipercard),
+    (340000, 349999, Bandeira::Amex),
+    (370000, 379999, Bandeira::Amex),
+    (300000, 305999, Bandeira::Diners),
+    (309500, 309599, Bandeira::Diners),
+    (360000, 369999, Bandeira::Diners),
+    (380000, 399999, Bandeira::Diners),
+    (352800, 358999, Bandeira::Jcb),
+    (601100, 601199, Bandeira::Discover),
+    (622126, 622925, Bandeira::Discover),
+    (644000, 649999, Bandeira::Discover),
+    (650000, 659999, Bandeira::Discover),
+    (222100, 272099, Bandeira::Mastercard),
+    (510000, 559999, Bandeira::Mastercard),
+    (400000, 499999, Bandeira::Visa),
+];
+
+impl Bandeira {
+    pub fn nome(&self) -> &'static str {
+        match self {
+            Bandeira::Visa => "Visa",
+            Bandeira::Mastercard => "Mastercard",
+            Bandeira::Amex => "American Express",
+            Bandeira::Elo => "Elo",
+            Bandeira::Hipercard => "Hipercard",
+            Bandeira::Diners => "Diners Club",
+            Bandeira::Discover => "Discover",
+            Bandeira::Jcb => "JCB",
+        }
+    }
+
+    /// Quantidades de dígitos emitidas pela bandeira
+    pub fn tamanhos(&self) -> &'static [usize] {
+        match self {
+            Bandeira::Visa => &[13, 16, 19],
+            Bandeira::Mastercard | Bandeira::Elo => &[16],
+            Bandeira::Amex => &[15],
+            Bandeira::Hipercard => &[13, 16, 19],
+            Bandeira::Diners => &[14, 15, 16, 17, 18, 19],
+            Bandeira::Discover | Bandeira::Jcb => &[16, 17, 18, 19],
+        }
+    }
+
+    /// Identifica a bandeira pelos seis primeiros dígitos do número, formatado ou não
+    pub fn detectar(numero: &str) -> Option<Bandeira> {
+        let digitos = somente_digitos(numero, 6);
+        if digitos.len() < 6 {
+            return None;
+        }
+        let bin = digitos.iter().fold(0, |acc, d| acc * 10 + d);
+        FAIXAS
+            .iter()
+            .find(|(inicio, fim, _)| (*inicio..=*fim).contains(&bin))
+            .map(|(_, _, bandeira)| *bandeira)
+    }
+}
+
+impl fmt::Display for Bandeira {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.nome())
+    }
+}
+
+impl CartaoCredito<'_> {
+    /// `None` para cartões de bandeiras desconhecidas, que são validados apenas pelo algoritmo de Luhn
+    pub fn bandeira(&self) -> Option<Bandeira> {
+        Bandeira::detectar(self.0)
+    }
+}
+
+impl CartaoCreditoBuf {
+    pub fn bandeira(&self) -> Option<Bandeira> {
+        Bandeira::detectar(self.as_str())
+    }
+}
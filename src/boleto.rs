@@ -0,0 +1,165 @@
+//! Boleto bancário, no padrão da FEBRABAN
+//! O código de barras possui 44 dígitos: banco, moeda, dígito verificador geral (módulo 11), fator de vencimento, valor e campo livre
+//! A linha digitável reorganiza os mesmos dígitos em cinco campos, os três primeiros com o próprio dígito verificador (módulo 10)
+//!```rust
+//! # use validador_br::formatar::Formatar;
+//! # use validador_br::types::{Boleto, Data};
+//! # use validador_br::validador::Validador;
+//! let boleto = Boleto("00190.50095 40144.816069 06809.350314 3 37370000000100");
+//! assert!(boleto.validar());
+//! assert_eq!(boleto.codigo_barras().unwrap(), "00193373700000001000500940144816060680935031");
+//! assert_eq!(boleto.banco().unwrap(), "001");
+//! assert_eq!(boleto.valor().unwrap(), 100);
+//! assert_eq!(boleto.vencimento_proximo(Data::new(2007, 12, 1).unwrap()).unwrap(), Data::new(2007, 12, 31));
+//!
+//! let barras = Boleto("00193373700000001000500940144816060680935031");
+//! assert_eq!(Boleto(&barras.linha_digitavel().unwrap()).formatar(), boleto.0);
+//!```
+
+use crate::erro::ErroValidacao;
+use crate::funcoes::{calc_digito, numero, onze_menos_mod11, texto, verificar_caracteres, verificar_quantidade};
+use crate::types::{Boleto, Data};
+use crate::validador::verificar_dv;
+
+/// Moeda indicada no 4º dígito para boletos em Real
+pub const MOEDA_REAL: u32 = 9;
+
+/// Data do fator 0000 no primeiro ciclo; o fator 1000 corresponde a 03/07/2000
+const DATA_BASE: Data = Data { ano: 1997, mes: 10, dia: 7 };
+
+/// Após o fator 9999, em 21/02/2025, a contagem recomeçou em 1000, de modo que cada ciclo avança 9000 dias
+const DIAS_POR_CICLO: i64 = 9000;
+
+/// Dígito verificador de um campo da linha digitável
+/// Da direita para a esquerda os pesos alternam entre 2 e 1, e produtos maiores que 9 têm seus algarismos somados
+pub(crate) fn dv_modulo_10(digitos: &[u32]) -> u32 {
+    let parcelas: Vec<u32> = digitos
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digito)| {
+            let produto = digito * (2 - index as u32 % 2);
+            produto / 10 + produto % 10
+        })
+        .collect();
+    calc_digito(parcelas, vec![1; digitos.len()], |soma| (10 - soma % 10) % 10)
+}
+
+/// Dígito verificador geral, calculado sobre os 43 demais dígitos do código de barras com pesos de 2 a 9 da direita para a esquerda
+/// Os restos que resultariam em 0, 10 ou 11 resultam no dígito 1
+fn dv_geral(digitos: &[u32]) -> u32 {
+    let multiplicadores = (0..digitos.len()).rev().map(|index| 2 + index as u32 % 8).collect();
+    calc_digito(digitos.to_vec(), multiplicadores, |soma| match onze_menos_mod11(soma) {
+        0 => 1,
+        dv => dv,
+    })
+}
+
+/// Linha digitável => código de barras
+fn linha_para_barras(linha: &[u32]) -> Vec<u32> {
+    [&linha[0..4], &linha[32..33], &linha[33..47], &linha[4..9], &linha[10..20], &linha[21..31]].concat()
+}
+
+/// Código de barras => linha digitável, calculando o dígito de cada campo
+fn barras_para_linha(barras: &[u32]) -> Vec<u32> {
+    let campo = |partes: &[&[u32]]| {
+        let mut campo = partes.concat();
+        campo.push(dv_modulo_10(&campo));
+        campo
+    };
+    [
+        campo(&[&barras[0..4], &barras[19..24]]),
+        campo(&[&barras[24..34]]),
+        campo(&[&barras[34..44]]),
+        vec![barras[4]],
+        barras[5..19].to_vec(),
+    ]
+    .concat()
+}
+
+impl Boleto<'_> {
+    /// Valida a linha digitável ou o código de barras e retorna os 44 dígitos do código de barras
+    pub(crate) fn digitos_barras(numero: &str) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_caracteres(numero)?;
+        let digitos = verificar_quantidade(numero, 44, 47)?;
+        let barras = match digitos.len() {
+            44 => digitos,
+            47 => {
+                for (inicio, fim) in [(0, 9), (10, 20), (21, 31)] {
+                    verificar_dv(vec![dv_modulo_10(&digitos[inicio..fim])], vec![digitos[fim]])?;
+                }
+                linha_para_barras(&digitos)
+            }
+            encontrado => {
+                return Err(ErroValidacao::QuantidadeDigitos {
+                    minimo: 44,
+                    maximo: 47,
+                    encontrado,
+                })
+            }
+        };
+        let sem_dv = [&barras[..4], &barras[5..]].concat();
+        verificar_dv(vec![dv_geral(&sem_dv)], vec![barras[4]])?;
+        Ok(barras)
+    }
+
+    /// Os 44 dígitos do código de barras
+    pub fn codigo_barras(&self) -> Result<String, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| texto(&barras))
+    }
+
+    /// Os 47 dígitos da linha digitável, sem formatação; use `Formatar::formatar` para separar os campos
+    pub fn linha_digitavel(&self) -> Result<String, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| texto(&barras_para_linha(&barras)))
+    }
+
+    /// Código do banco emissor na Câmara de Compensação, como `001` para o Banco do Brasil
+    pub fn banco(&self) -> Result<String, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| texto(&barras[0..3]))
+    }
+
+    /// `MOEDA_REAL` para boletos em Real
+    pub fn moeda(&self) -> Result<u32, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| barras[3])
+    }
+
+    /// Dias desde a data base; `None` quando o boleto não possui vencimento, indicado pelo fator 0000
+    pub fn fator_vencimento(&self) -> Result<Option<u16>, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| match numero(&barras[5..9]) {
+            0 => None,
+            fator => Some(fator as u16),
+        })
+    }
+
+    /// Vencimento no ciclo atual do fator, iniciado em 22/02/2025 com o fator 1000
+    /// Para boletos emitidos antes dessa data use `vencimento_proximo`
+    pub fn vencimento(&self) -> Result<Option<Data>, ErroValidacao> {
+        self.fator_vencimento()
+            .map(|fator| fator.map(|fator| vencimento_no_ciclo(fator, 1)))
+    }
+
+    /// Vencimento no ciclo do fator mais próximo da data de referência, como a data de emissão ou de pagamento
+    pub fn vencimento_proximo(&self, referencia: Data) -> Result<Option<Data>, ErroValidacao> {
+        self.fator_vencimento().map(|fator| {
+            fator.map(|fator| {
+                let dias = DATA_BASE.dias_ate(&referencia) - fator as i64;
+                let ciclo = (dias + DIAS_POR_CICLO / 2).div_euclid(DIAS_POR_CICLO).max(0);
+                vencimento_no_ciclo(fator, ciclo)
+            })
+        })
+    }
+
+    /// Valor em centavos; zero quando o valor é informado no momento do pagamento
+    pub fn valor(&self) -> Result<u64, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| numero(&barras[9..19]))
+    }
+
+    /// Os 25 dígitos de uso livre do banco, como agência, conta e nosso número
+    pub fn campo_livre(&self) -> Result<String, ErroValidacao> {
+        Self::digitos_barras(self.0).map(|barras| texto(&barras[19..44]))
+    }
+}
+
+fn vencimento_no_ciclo(fator: u16, ciclo: i64) -> Data {
+    DATA_BASE.somar_dias(fator as i64 + ciclo * DIAS_POR_CICLO)
+}
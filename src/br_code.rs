@@ -0,0 +1,321 @@
+//! Código PIX copia e cola (BR Code): leitura, validação e geração do payload EMV
+//! O payload é uma sequência de campos no formato ID, tamanho com 2 dígitos e valor, terminada pelo CRC16 do campo 63
+//! Na leitura são verificados o CRC, os campos obrigatórios, o GUI `br.gov.bcb.pix` e a `ChavePix`
+//!```rust
+//! # use validador_br::types::{BrCode, ChavePix};
+//! let mut pix = BrCode::new(ChavePix::try_from("+5511987654321").unwrap(), "Fulano de Tal", "São Paulo");
+//! pix.valor = Some(1050);
+//! let payload = pix.codificar().unwrap();
+//! assert!(payload.starts_with("000201"));
+//! assert!(payload.contains("540510.50"));
+//!
+//! let lido = BrCode::try_from(payload.as_str()).unwrap();
+//! assert_eq!(lido.valor, Some(1050));
+//! assert_eq!(lido.cidade, "Sao Paulo");
+//! assert_eq!(lido.chave.unwrap().as_str(), "+5511987654321");
+//!```
+
+use std::str::FromStr;
+
+use crate::erro::{campo_invalido, ErroValidacao};
+use crate::types::{BrCode, ChavePix};
+use crate::validador::{PoliticaRepeticao, Validador};
+
+/// Identificador do arranjo PIX no campo 26
+pub const GUI_PIX: &str = "br.gov.bcb.pix";
+
+/// Código ISO 4217 do Real, no campo 53
+const MOEDA_REAL: &str = "986";
+
+/// TxId do PIX estático sem identificador
+const SEM_TXID: &str = "***";
+
+/// Maior valor em centavos que cabe nos 13 caracteres do campo 54, `9999999999.99`
+pub const VALOR_MAXIMO: u64 = 999_999_999_999;
+
+/// CRC16-CCITT, com polinômio `0x1021` e valor inicial `0xFFFF`, calculado sobre todo o payload até o `6304` inclusive
+///```rust
+/// # use validador_br::br_code::crc16;
+///assert_eq!(crc16(b"123456789"), 0x29B1);
+///```
+pub fn crc16(dados: &[u8]) -> u16 {
+    dados.iter().fold(0xFFFF, |crc, &byte| {
+        (0..8).fold(crc ^ (byte as u16) << 8, |crc, _| {
+            if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+/// Separa os campos `ID`, tamanho e valor
+fn campos(payload: &str) -> Result<Vec<(&str, &str)>, ErroValidacao> {
+    let mut campos = Vec::new();
+    let mut resto = payload;
+    while !resto.is_empty() {
+        let tamanho = resto
+            .get(2..4)
+            .filter(|tamanho| tamanho.bytes().all(|c| c.is_ascii_digit()))
+            .and_then(|tamanho| tamanho.parse::<usize>().ok())
+            .filter(|tamanho| 4 + tamanho <= resto.len())
+            .ok_or_else(|| campo_invalido("TLV", resto))?;
+        campos.push((&resto[..2], &resto[4..4 + tamanho]));
+        resto = &resto[4 + tamanho..];
+    }
+    Ok(campos)
+}
+
+fn buscar<'a>(campos: &[(&str, &'a str)], id: &str) -> Option<&'a str> {
+    campos.iter().find(|(campo, _)| *campo == id).map(|(_, valor)| *valor)
+}
+
+fn obrigatorio<'a>(campos: &[(&str, &'a str)], id: &str, nome: &'static str) -> Result<&'a str, ErroValidacao> {
+    buscar(campos, id).ok_or_else(|| campo_invalido(nome, ""))
+}
+
+/// `10.50` => 1050, com até duas casas decimais
+fn ler_valor(valor: &str) -> Result<u64, ErroValidacao> {
+    let (reais, centavos) = valor.split_once('.').unwrap_or((valor, ""));
+    let digitos = |texto: &str| texto.bytes().all(|c| c.is_ascii_digit());
+    if valor.len() > 13 || reais.is_empty() || centavos.len() > 2 || !digitos(reais) || !digitos(centavos) {
+        return Err(campo_invalido("valor", valor));
+    }
+    let reais: u64 = reais.parse().map_err(|_| campo_invalido("valor", valor))?;
+    let centavos: u64 = format!("{centavos:0<2}").parse().unwrap_or(0);
+    match reais * 100 + centavos {
+        total if total <= VALOR_MAXIMO => Ok(total),
+        _ => Err(campo_invalido("valor", valor)),
+    }
+}
+
+/// Troca as letras acentuadas do português pela letra sem acento, já que o payload aceita apenas ASCII
+fn sem_acentos(texto: &str) -> String {
+    texto
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'Á' | 'À' | 'Â' | 'Ã' | 'Ä' => 'A',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'É' | 'È' | 'Ê' | 'Ë' => 'E',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'Í' | 'Ì' | 'Î' | 'Ï' => 'I',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ö' => 'O',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'Ú' | 'Ù' | 'Û' | 'Ü' => 'U',
+            'ç' => 'c',
+            'Ç' => 'C',
+            c => c,
+        })
+        .collect()
+}
+
+/// `ID`, tamanho e valor; o valor deve ter no máximo 99 caracteres ASCII
+fn campo(id: &str, nome: &'static str, valor: &str) -> Result<String, ErroValidacao> {
+    if valor.len() > 99 || !valor.is_ascii() {
+        return Err(campo_invalido(nome, valor));
+    }
+    Ok(format!("{id}{:02}{valor}", valor.len()))
+}
+
+/// Até 25 letras e dígitos
+fn verificar_txid(txid: &str) -> Result<(), ErroValidacao> {
+    if (1..=25).contains(&txid.len()) && txid.bytes().all(|c| c.is_ascii_alphanumeric()) {
+        Ok(())
+    } else {
+        Err(campo_invalido("txid", txid))
+    }
+}
+
+/// Texto com tamanho entre 1 e `maximo`
+fn texto_limitado(nome: &'static str, valor: &str, maximo: usize) -> Result<String, ErroValidacao> {
+    let valor = sem_acentos(valor.trim());
+    if (1..=maximo).contains(&valor.len()) {
+        Ok(valor)
+    } else {
+        Err(campo_invalido(nome, &valor))
+    }
+}
+
+impl BrCode {
+    /// PIX estático, sem valor e sem txid
+    pub fn new(chave: ChavePix, nome: &str, cidade: &str) -> BrCode {
+        BrCode {
+            chave: Some(chave),
+            ..BrCode::recebedor(nome, cidade)
+        }
+    }
+
+    /// PIX dinâmico, com a URL da cobrança gerada pelo PSP do recebedor
+    pub fn dinamico(url: &str, nome: &str, cidade: &str) -> BrCode {
+        BrCode {
+            url: Some(url.to_string()),
+            ..BrCode::recebedor(nome, cidade)
+        }
+    }
+
+    fn recebedor(nome: &str, cidade: &str) -> BrCode {
+        BrCode {
+            chave: None,
+            url: None,
+            info_adicional: None,
+            valor: None,
+            nome: nome.to_string(),
+            cidade: cidade.to_string(),
+            txid: None,
+            unico: false,
+        }
+    }
+
+    /// Gera o payload com o CRC16; acentos do nome e da cidade são removidos
+    pub fn codificar(&self) -> Result<String, ErroValidacao> {
+        if self.chave.is_none() && self.url.is_none() {
+            return Err(campo_invalido("chave", ""));
+        }
+        let mut conta = campo("00", "GUI", GUI_PIX)?;
+        if let Some(chave) = &self.chave {
+            conta += &campo("01", "chave", chave.as_str())?;
+        }
+        if let Some(info_adicional) = &self.info_adicional {
+            conta += &campo("02", "informação adicional", &sem_acentos(info_adicional))?;
+        }
+        if let Some(url) = &self.url {
+            conta += &campo("25", "url", url)?;
+        }
+        let txid = match &self.txid {
+            Some(txid) => {
+                verificar_txid(txid)?;
+                txid
+            }
+            None => SEM_TXID,
+        };
+
+        let mut payload = campo("00", "formato", "01")?;
+        if self.unico {
+            payload += &campo("01", "uso único", "12")?;
+        }
+        payload += &campo("26", "conta", &conta)?;
+        payload += &campo("52", "categoria", "0000")?;
+        payload += &campo("53", "moeda", MOEDA_REAL)?;
+        if let Some(valor) = self.valor {
+            if valor > VALOR_MAXIMO {
+                return Err(campo_invalido("valor", valor));
+            }
+            payload += &campo("54", "valor", &format!("{}.{:02}", valor / 100, valor % 100))?;
+        }
+        payload += &campo("58", "país", "BR")?;
+        payload += &campo("59", "nome", &texto_limitado("nome", &self.nome, 25)?)?;
+        payload += &campo("60", "cidade", &texto_limitado("cidade", &self.cidade, 15)?)?;
+        payload += &campo("62", "dados adicionais", &campo("05", "txid", txid)?)?;
+        payload += "6304";
+        let crc = crc16(payload.as_bytes());
+        Ok(format!("{payload}{crc:04X}"))
+    }
+}
+
+/// Lê o payload, verificando o CRC16, os campos obrigatórios e a chave; espaços e quebras de linha nas pontas são ignorados
+impl TryFrom<&str> for BrCode {
+    type Error = ErroValidacao;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let payload = value.trim();
+        if let Some((posicao, caractere)) = payload.chars().enumerate().find(|(_, c)| !c.is_ascii() || c.is_ascii_control()) {
+            return Err(ErroValidacao::CaractereInvalido { caractere, posicao });
+        }
+        let (dados, crc) = payload.split_at(payload.len().saturating_sub(4));
+        if !dados.ends_with("6304") || !crc.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(campo_invalido("CRC", payload));
+        }
+        let hexadecimal = |texto: &str| texto.chars().map(|c| c.to_digit(16).unwrap_or(0)).collect::<Vec<u32>>();
+        let esperado = format!("{:04X}", crc16(dados.as_bytes()));
+        if !esperado.eq_ignore_ascii_case(crc) {
+            return Err(ErroValidacao::DigitoVerificador {
+                esperado: hexadecimal(&esperado),
+                encontrado: hexadecimal(crc),
+            });
+        }
+
+        let campos = campos(payload)?;
+        if campos.first() != Some(&("00", "01")) {
+            return Err(campo_invalido("formato", campos.first().map_or("", |(_, valor)| valor)));
+        }
+        let unico = match buscar(&campos, "01") {
+            None | Some("11") => false,
+            Some("12") => true,
+            Some(outro) => return Err(campo_invalido("uso único", outro)),
+        };
+
+        let conta = self::campos(obrigatorio(&campos, "26", "conta")?)?;
+        let gui = obrigatorio(&conta, "00", "GUI")?;
+        if !gui.eq_ignore_ascii_case(GUI_PIX) {
+            return Err(campo_invalido("GUI", gui));
+        }
+        let chave = buscar(&conta, "01").map(ChavePix::try_from).transpose()?;
+        let url = buscar(&conta, "25").map(str::to_string);
+        if chave.is_none() && url.is_none() {
+            return Err(campo_invalido("chave", ""));
+        }
+
+        obrigatorio(&campos, "52", "categoria")?;
+        let moeda = obrigatorio(&campos, "53", "moeda")?;
+        if moeda != MOEDA_REAL {
+            return Err(campo_invalido("moeda", moeda));
+        }
+        let pais = obrigatorio(&campos, "58", "país")?;
+        if pais != "BR" {
+            return Err(campo_invalido("país", pais));
+        }
+        // os mesmos limites de `codificar`, para que todo payload lido possa ser gerado novamente
+        let nome = obrigatorio(&campos, "59", "nome")?;
+        texto_limitado("nome", nome, 25)?;
+        let cidade = obrigatorio(&campos, "60", "cidade")?;
+        texto_limitado("cidade", cidade, 15)?;
+        let txid = match buscar(&campos, "62") {
+            Some(dados) => buscar(&self::campos(dados)?, "05").filter(|txid| *txid != SEM_TXID).map(str::to_string),
+            None => None,
+        };
+        if let Some(txid) = &txid {
+            verificar_txid(txid)?;
+        }
+
+        Ok(BrCode {
+            chave,
+            url,
+            info_adicional: buscar(&conta, "02").map(str::to_string),
+            valor: buscar(&campos, "54").map(ler_valor).transpose()?,
+            nome: nome.to_string(),
+            cidade: cidade.to_string(),
+            txid,
+            unico,
+        })
+    }
+}
+
+impl TryFrom<String> for BrCode {
+    type Error = ErroValidacao;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for BrCode {
+    type Err = ErroValidacao;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+/// Verifica um payload copia e cola
+impl Validador for BrCode {
+    fn verificar_com(numero: &str, _politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        BrCode::try_from(numero).map(|_| ())
+    }
+
+    fn validar(&self) -> bool {
+        self.codificar().is_ok()
+    }
+}
@@ -0,0 +1,177 @@
+//! Chave de acesso dos documentos fiscais eletrônicos
+//! Os 44 dígitos são formados por UF, ano e mês de emissão, CNPJ ou CPF do emitente, modelo, série, número, forma de emissão, código numérico e dígito verificador
+//!```rust
+//! # use validador_br::types::{ChaveAcesso, Emitente, ModeloFiscal, Uf};
+//! # use validador_br::validador::Validador;
+//! let chave = ChaveAcesso("3523 0576 5534 1200 0110 5500 1000 0012 3410 0000 0014");
+//! assert!(chave.validar());
+//!
+//! let partes = chave.partes().unwrap();
+//! assert_eq!(partes.uf, Uf::SP);
+//! assert_eq!(partes.modelo, ModeloFiscal::Nfe);
+//! assert_eq!(partes.numero, 1234);
+//! assert!(matches!(&partes.emitente, Emitente::Cnpj(cnpj) if cnpj.as_str() == "76553412000110"));
+//! assert_eq!(partes.montar().unwrap(), "35230576553412000110550010000012341000000014");
+//!```
+
+use std::fmt;
+
+use crate::erro::{campo_invalido, ErroValidacao};
+use crate::funcoes::{numero, somente_alfanumericos, texto, verificar_caracteres_alfanumericos,
+    verificar_quantidade_alfanumericos,
+};
+use crate::gerador_dv::GeradorDv;
+use crate::owned::{CnpjBuf, CpfBuf};
+use crate::types::{ChaveAcesso, Emitente, ModeloFiscal, PartesChaveAcesso, Uf};
+use crate::validador::verificar_dv;
+
+impl ModeloFiscal {
+    pub const TODOS: [ModeloFiscal; 5] = [
+        ModeloFiscal::Nfe,
+        ModeloFiscal::Nfce,
+        ModeloFiscal::Cte,
+        ModeloFiscal::Mdfe,
+        ModeloFiscal::CteOs,
+    ];
+
+    pub fn codigo(&self) -> u8 {
+        match self {
+            ModeloFiscal::Nfe => 55,
+            ModeloFiscal::Nfce => 65,
+            ModeloFiscal::Cte => 57,
+            ModeloFiscal::Mdfe => 58,
+            ModeloFiscal::CteOs => 67,
+        }
+    }
+
+    pub fn from_codigo(codigo: u8) -> Option<ModeloFiscal> {
+        ModeloFiscal::TODOS.into_iter().find(|modelo| modelo.codigo() == codigo)
+    }
+
+    pub fn nome(&self) -> &'static str {
+        match self {
+            ModeloFiscal::Nfe => "NF-e",
+            ModeloFiscal::Nfce => "NFC-e",
+            ModeloFiscal::Cte => "CT-e",
+            ModeloFiscal::Mdfe => "MDF-e",
+            ModeloFiscal::CteOs => "CT-e OS",
+        }
+    }
+}
+
+/// `NF-e`
+impl fmt::Display for ModeloFiscal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.nome())
+    }
+}
+
+impl Emitente {
+    /// Os 14 dígitos do campo na chave de acesso, com o CPF precedido de `000`
+    fn campo(&self) -> String {
+        match self {
+            Emitente::Cnpj(cnpj) => cnpj.as_str().to_string(),
+            Emitente::Cpf(cpf) => format!("000{}", cpf.as_str()),
+        }
+    }
+
+    /// Um CNPJ válido tem prioridade; caso contrário, os 11 últimos dígitos precedidos de `000` são lidos como CPF
+    fn from_campo(campo: &str) -> Result<Emitente, ErroValidacao> {
+        match CnpjBuf::try_from(campo) {
+            Ok(cnpj) => Ok(Emitente::Cnpj(cnpj)),
+            Err(erro) => match campo.strip_prefix("000").map(CpfBuf::try_from) {
+                Some(Ok(cpf)) => Ok(Emitente::Cpf(cpf)),
+                _ => Err(erro),
+            },
+        }
+    }
+}
+
+/// Posições do CNPJ do emitente que aceitam letras; os dígitos verificadores do CNPJ e os demais campos são numéricos
+const POSICOES_LETRAS: std::ops::Range<usize> = 6..18;
+
+/// Verifica se as letras do CNPJ alfanumérico aparecem somente na raiz e na ordem do emitente
+pub(crate) fn verificar_letras(numero: &str) -> Result<(), ErroValidacao> {
+    match numero
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| c.is_ascii_alphanumeric())
+        .enumerate()
+        .find(|(indice, (_, c))| !c.is_ascii_digit() && !POSICOES_LETRAS.contains(indice))
+    {
+        Some((_, (posicao, caractere))) => Err(ErroValidacao::CaractereInvalido { caractere, posicao }),
+        None => Ok(()),
+    }
+}
+
+impl ChaveAcesso<'_> {
+    /// Valida o dígito verificador, a UF, o mês, o modelo e o documento do emitente, retornando os campos
+    /// O emitente pode ter CNPJ alfanumérico; as letras entram no cálculo do dígito verificador pelo código ASCII menos 48
+    pub fn partes(&self) -> Result<PartesChaveAcesso, ErroValidacao> {
+        verificar_caracteres_alfanumericos(self.0)?;
+        let mut digitos = verificar_quantidade_alfanumericos(self.0, 44, 44)?;
+        verificar_letras(self.0)?;
+        let dv = digitos.split_off(43);
+        verificar_dv(Self::calcular_dv(&digitos)?, dv)?;
+
+        let uf = Uf::from_codigo_ibge(numero(&digitos[0..2]) as u8).ok_or_else(|| ErroValidacao::UfInvalida {
+            encontrado: texto(&digitos[0..2]),
+        })?;
+        let mes = numero(&digitos[4..6]) as u8;
+        if !(1..=12).contains(&mes) {
+            return Err(campo_invalido("mês", texto(&digitos[4..6])));
+        }
+        let emitente = Emitente::from_campo(&somente_alfanumericos(self.0)[6..20])?;
+        let modelo = ModeloFiscal::from_codigo(numero(&digitos[20..22]) as u8)
+            .ok_or_else(|| campo_invalido("modelo", texto(&digitos[20..22])))?;
+        Ok(PartesChaveAcesso {
+            uf,
+            ano: 2000 + numero(&digitos[2..4]) as u16,
+            mes,
+            emitente,
+            modelo,
+            serie: numero(&digitos[22..25]) as u16,
+            numero: numero(&digitos[25..34]) as u32,
+            tipo_emissao: digitos[34] as u8,
+            codigo_numerico: numero(&digitos[35..43]) as u32,
+        })
+    }
+}
+
+impl PartesChaveAcesso {
+    /// Monta os 44 caracteres da chave, calculando o dígito verificador
+    pub fn montar(&self) -> Result<String, ErroValidacao> {
+        if !(2000..=2099).contains(&self.ano) {
+            return Err(campo_invalido("ano", self.ano));
+        }
+        if !(1..=12).contains(&self.mes) {
+            return Err(campo_invalido("mês", self.mes));
+        }
+        if self.serie > 999 {
+            return Err(campo_invalido("série", self.serie));
+        }
+        if self.numero > 999_999_999 {
+            return Err(campo_invalido("número", self.numero));
+        }
+        if self.tipo_emissao > 9 {
+            return Err(campo_invalido("forma de emissão", self.tipo_emissao));
+        }
+        if self.codigo_numerico > 99_999_999 {
+            return Err(campo_invalido("código numérico", self.codigo_numerico));
+        }
+        let base = format!(
+            "{:02}{:02}{:02}{}{:02}{:03}{:09}{}{:08}",
+            self.uf.codigo_ibge(),
+            self.ano % 100,
+            self.mes,
+            self.emitente.campo(),
+            self.modelo.codigo(),
+            self.serie,
+            self.numero,
+            self.tipo_emissao,
+            self.codigo_numerico,
+        );
+        let dv = ChaveAcesso::gerar_dv(&base)?;
+        Ok(format!("{base}{}", texto(&dv)))
+    }
+}
@@ -0,0 +1,100 @@
+//! Estrutura do CNPJ: os 8 primeiros caracteres formam a raiz, que identifica a empresa, e os 4 seguintes a ordem do estabelecimento
+//! A matriz tem ordem `0001` e as filiais `0002` em diante
+//!```rust
+//! # use validador_br::owned::CnpjBuf;
+//! # use validador_br::types::Cnpj;
+//! assert_eq!(Cnpj("76.553.412/0001-10").raiz().unwrap(), "76553412");
+//! assert!(Cnpj("76.553.412/0001-10").is_matriz());
+//!
+//! let filial = CnpjBuf::from_raiz("76.553.412", "0002").unwrap();
+//! assert_eq!(filial.to_string(), "76.553.412/0002-00");
+//! assert!(!filial.is_matriz());
+//! assert!(filial.mesma_empresa(&"76553412000110".parse().unwrap()));
+//!```
+
+use crate::erro::{campo_invalido, ErroValidacao};
+use crate::formatar::Formatar;
+use crate::funcoes::somente_alfanumericos;
+use crate::gerador_dv::GeradorDv;
+use crate::owned::CnpjBuf;
+use crate::types::Cnpj;
+use crate::validador::Validador;
+
+/// Ordem do estabelecimento matriz
+pub const ORDEM_MATRIZ: &str = "0001";
+
+impl Cnpj<'_> {
+    fn canonico_valido(&self) -> Result<String, ErroValidacao> {
+        Self::verificar(self.0)?;
+        Ok(self.canonico())
+    }
+
+    /// Os 8 primeiros caracteres, comuns à matriz e às filiais; o CNPJ é validado antes
+    pub fn raiz(&self) -> Result<String, ErroValidacao> {
+        self.canonico_valido().map(|canonico| canonico[..8].to_string())
+    }
+
+    /// Os 4 caracteres que identificam o estabelecimento, `0001` para a matriz
+    pub fn ordem(&self) -> Result<String, ErroValidacao> {
+        self.canonico_valido().map(|canonico| canonico[8..12].to_string())
+    }
+
+    /// Retorna `false` também para CNPJs inválidos
+    pub fn is_matriz(&self) -> bool {
+        self.ordem().is_ok_and(|ordem| ordem == ORDEM_MATRIZ)
+    }
+
+    /// Verifica se os dois CNPJs são válidos e possuem a mesma raiz
+    pub fn mesma_empresa(&self, outro: &Cnpj) -> bool {
+        matches!((self.raiz(), outro.raiz()), (Ok(raiz), Ok(outra)) if raiz == outra)
+    }
+}
+
+impl CnpjBuf {
+    pub fn raiz(&self) -> &str {
+        &self.as_str()[..8]
+    }
+
+    pub fn ordem(&self) -> &str {
+        &self.as_str()[8..12]
+    }
+
+    pub fn is_matriz(&self) -> bool {
+        self.ordem() == ORDEM_MATRIZ
+    }
+
+    pub fn mesma_empresa(&self, outro: &CnpjBuf) -> bool {
+        self.raiz() == outro.raiz()
+    }
+
+    /// Monta o CNPJ a partir da raiz e da ordem, numéricas ou alfanuméricas, calculando os dígitos verificadores
+    /// A ordem `0000` não identifica nenhum estabelecimento e retorna `ErroValidacao::CampoInvalido`
+    pub fn from_raiz(raiz: &str, ordem: &str) -> Result<CnpjBuf, ErroValidacao> {
+        let raiz = parte(raiz, 8)?;
+        let ordem = parte(ordem, 4)?;
+        if ordem == "0000" {
+            return Err(campo_invalido("ordem", ordem));
+        }
+        let completo = Cnpj::completar(&format!("{raiz}{ordem}"))?;
+        CnpjBuf::try_from(completo.as_str())
+    }
+
+    /// Outro estabelecimento da mesma empresa, `0001` para a matriz
+    pub fn filial(&self, ordem: &str) -> Result<CnpjBuf, ErroValidacao> {
+        CnpjBuf::from_raiz(self.raiz(), ordem)
+    }
+}
+
+/// Normaliza a raiz ou a ordem, verificando a quantidade de caracteres
+fn parte(texto: &str, tamanho: usize) -> Result<String, ErroValidacao> {
+    let parte = somente_alfanumericos(texto);
+    if parte.len() == tamanho {
+        Ok(parte)
+    } else {
+        Err(ErroValidacao::QuantidadeDigitos {
+            minimo: tamanho,
+            maximo: tamanho,
+            encontrado: parte.len(),
+        })
+    }
+}
@@ -0,0 +1,75 @@
+//! Operações mínimas sobre `Data`, suficientes para converter o fator de vencimento do boleto
+//!```rust
+//! # use validador_br::types::Data;
+//! let data = Data::new(2025, 2, 22).unwrap();
+//! assert_eq!(data.to_string(), "22/02/2025");
+//! assert_eq!(data.somar_dias(7), Data::new(2025, 3, 1).unwrap());
+//! assert!(Data::new(2025, 2, 29).is_none());
+//!```
+
+use std::fmt;
+
+use crate::types::Data;
+
+impl Data {
+    /// Retorna `None` se o dia não existir no mês informado
+    // `u16::is_multiple_of` só é estável a partir do Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn new(ano: u16, mes: u8, dia: u8) -> Option<Data> {
+        let bissexto = ano % 4 == 0 && (ano % 100 != 0 || ano % 400 == 0);
+        let dias_no_mes = match mes {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if bissexto => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=dias_no_mes).contains(&dia).then_some(Data { ano, mes, dia })
+    }
+
+    /// Dias desde 01/01/1970
+    pub(crate) fn dias(&self) -> i64 {
+        // algoritmo days_from_civil, com o ano iniciando em março
+        let (ano, mes, dia) = (self.ano as i64, self.mes as i64, self.dia as i64);
+        let ano = if mes <= 2 { ano - 1 } else { ano };
+        let era = ano.div_euclid(400);
+        let ano_da_era = ano - era * 400;
+        let dia_do_ano = (153 * ((mes + 9) % 12) + 2) / 5 + dia - 1;
+        let dia_da_era = ano_da_era * 365 + ano_da_era / 4 - ano_da_era / 100 + dia_do_ano;
+        era * 146097 + dia_da_era - 719468
+    }
+
+    /// Inverso de `dias()`
+    pub(crate) fn de_dias(dias: i64) -> Data {
+        let dias = dias + 719468;
+        let era = dias.div_euclid(146097);
+        let dia_da_era = dias - era * 146097;
+        let ano_da_era = (dia_da_era - dia_da_era / 1460 + dia_da_era / 36524 - dia_da_era / 146096) / 365;
+        let dia_do_ano = dia_da_era - (365 * ano_da_era + ano_da_era / 4 - ano_da_era / 100);
+        let mes = (5 * dia_do_ano + 2) / 153;
+        let dia = dia_do_ano - (153 * mes + 2) / 5 + 1;
+        let mes = if mes < 10 { mes + 3 } else { mes - 9 };
+        let ano = ano_da_era + era * 400 + i64::from(mes <= 2);
+        Data {
+            ano: ano as u16,
+            mes: mes as u8,
+            dia: dia as u8,
+        }
+    }
+
+    pub fn somar_dias(&self, dias: i64) -> Data {
+        Data::de_dias(self.dias() + dias)
+    }
+
+    /// Quantidade de dias até a outra data, negativa se ela for anterior
+    pub fn dias_ate(&self, outra: &Data) -> i64 {
+        outra.dias() - self.dias()
+    }
+}
+
+/// `22/02/2025`
+impl fmt::Display for Data {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}/{:02}/{:04}", self.dia, self.mes, self.ano)
+    }
+}
@@ -0,0 +1,287 @@
+//! Métodos do tipo `Documento`, que representa qualquer um dos documentos suportados
+//! O tipo pode ser informado junto com o número, como em `cpf:255.248.930-33`, ou detectado a partir de um campo livre, como um campo "CPF ou CNPJ"
+//! Na detecção cada tipo é testado pela quantidade de dígitos, pelos prefixos aceitos e pelo dígito verificador
+//!```rust
+//! # use validador_br::types::{Documento, TipoDocumento};
+//! let documento: Documento = "cpf:25524893033".parse().unwrap();
+//! assert_eq!(documento.tipo(), TipoDocumento::Cpf);
+//! assert_eq!(documento.to_string(), "cpf:255.248.930-33");
+//!
+//! let candidatos = Documento::detectar("255.248.930-33");
+//! assert_eq!(candidatos[0], documento);
+//! assert!(Documento::detectar("76.553.412/0001-10").iter().any(|d| d.tipo() == TipoDocumento::Cnpj));
+//! assert!(Documento::detectar("255.248.930-35").is_empty());
+//!```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::erro::ErroValidacao;
+use crate::formatar::Formatar;
+use crate::owned::{CartaoCreditoBuf, CnhBuf, CnpjBuf, CnsBuf, CodigoBarrasGs1Buf, CpfBuf, IeBuf,
+    PisBuf, RenavamBuf, RgBuf, TituloEleitorBuf,
+};
+use crate::types::{CartaoCredito, Cnh, Cnpj, Cns, CodigoBarrasGs1, Cpf, Documento, Pis, Renavam,
+    Rg, TipoDocumento, TituloEleitor,
+};
+use crate::validador::{PoliticaRepeticao, Validador};
+use crate::validador_ie::IeUf;
+
+impl TipoDocumento {
+    /// Todos os tipos, na ordem usada como desempate por `Documento::detectar`
+    /// Documentos pessoais vêm primeiro; códigos de barras e cartões de crédito, que usam apenas um dígito verificador, vêm por último
+    pub const TODOS: [TipoDocumento; 11] = [
+        TipoDocumento::Cpf,
+        TipoDocumento::Cnpj,
+        TipoDocumento::Cns,
+        TipoDocumento::Pis,
+        TipoDocumento::TituloEleitor,
+        TipoDocumento::Cnh,
+        TipoDocumento::Renavam,
+        TipoDocumento::Rg,
+        TipoDocumento::Ie,
+        TipoDocumento::CodigoBarrasGs1,
+        TipoDocumento::CartaoCredito,
+    ];
+
+    /// Identificador usado antes do número, como em `cpf:255.248.930-33`
+    pub fn identificador(&self) -> &'static str {
+        match self {
+            TipoDocumento::Cpf => "cpf",
+            TipoDocumento::Cnpj => "cnpj",
+            TipoDocumento::Cns => "cns",
+            TipoDocumento::Pis => "pis",
+            TipoDocumento::TituloEleitor => "titulo",
+            TipoDocumento::Cnh => "cnh",
+            TipoDocumento::Renavam => "renavam",
+            TipoDocumento::Rg => "rg",
+            TipoDocumento::Ie => "ie",
+            TipoDocumento::CodigoBarrasGs1 => "gs1",
+            TipoDocumento::CartaoCredito => "cartao",
+        }
+    }
+
+    pub fn nome(&self) -> &'static str {
+        match self {
+            TipoDocumento::Cpf => "Cadastro de Pessoa Física",
+            TipoDocumento::Cnpj => "Cadastro Nacional da Pessoa Jurídica",
+            TipoDocumento::Cns => "Cartão Nacional de Saúde",
+            TipoDocumento::Pis => "Pis, Pasep ou Nit",
+            TipoDocumento::TituloEleitor => "Título de Eleitor",
+            TipoDocumento::Cnh => "Carteira Nacional de Habilitação",
+            TipoDocumento::Renavam => "Registro Nacional de Veículos Automotores",
+            TipoDocumento::Rg => "Registro Geral",
+            TipoDocumento::Ie => "Inscrição Estadual",
+            TipoDocumento::CodigoBarrasGs1 => "Código de Barras Gs1",
+            TipoDocumento::CartaoCredito => "Cartão de Crédito",
+        }
+    }
+
+    /// Quanto menor, mais restrito o formato: prefixo ou código embutido, tamanho fixo e tamanho variável
+    fn restricao(&self) -> u8 {
+        match self {
+            TipoDocumento::Cns | TipoDocumento::TituloEleitor | TipoDocumento::Ie => 0,
+            TipoDocumento::Cpf | TipoDocumento::Cnpj | TipoDocumento::Pis | TipoDocumento::Cnh | TipoDocumento::Rg => 1,
+            TipoDocumento::Renavam | TipoDocumento::CodigoBarrasGs1 | TipoDocumento::CartaoCredito => 2,
+        }
+    }
+
+    /// Verifica o número com o algoritmo do tipo; a Inscrição Estadual deve vir precedida da UF
+    pub(crate) fn verificar_com(&self, numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        match self {
+            TipoDocumento::Cpf => Cpf::verificar_com(numero, politica),
+            TipoDocumento::Cnpj => Cnpj::verificar_com(numero, politica),
+            TipoDocumento::Cns => Cns::verificar_com(numero, politica),
+            TipoDocumento::Pis => Pis::verificar_com(numero, politica),
+            TipoDocumento::TituloEleitor => TituloEleitor::verificar_com(numero, politica),
+            TipoDocumento::Cnh => Cnh::verificar_com(numero, politica),
+            TipoDocumento::Renavam => Renavam::verificar_com(numero, politica),
+            TipoDocumento::Rg => Rg::verificar_com(numero, politica),
+            TipoDocumento::Ie => IeUf::verificar_com(numero, politica),
+            TipoDocumento::CodigoBarrasGs1 => CodigoBarrasGs1::verificar_com(numero, politica),
+            TipoDocumento::CartaoCredito => CartaoCredito::verificar_com(numero, politica),
+        }
+    }
+}
+
+/// Aceita o identificador em maiúsculas ou minúsculas e alguns nomes alternativos, como `pasep`, `nit` e `ean`
+impl FromStr for TipoDocumento {
+    type Err = ErroValidacao;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "cpf" => Ok(TipoDocumento::Cpf),
+            "cnpj" => Ok(TipoDocumento::Cnpj),
+            "cns" => Ok(TipoDocumento::Cns),
+            "pis" | "pasep" | "nit" => Ok(TipoDocumento::Pis),
+            "titulo" | "titulo_eleitor" => Ok(TipoDocumento::TituloEleitor),
+            "cnh" => Ok(TipoDocumento::Cnh),
+            "renavam" => Ok(TipoDocumento::Renavam),
+            "rg" => Ok(TipoDocumento::Rg),
+            "ie" => Ok(TipoDocumento::Ie),
+            "gs1" | "ean" | "gtin" => Ok(TipoDocumento::CodigoBarrasGs1),
+            "cartao" | "cartao_credito" => Ok(TipoDocumento::CartaoCredito),
+            _ => Err(ErroValidacao::TipoInvalido {
+                encontrado: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for TipoDocumento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.identificador())
+    }
+}
+
+impl Documento {
+    pub fn tipo(&self) -> TipoDocumento {
+        match self {
+            Documento::Cpf(_) => TipoDocumento::Cpf,
+            Documento::Cnpj(_) => TipoDocumento::Cnpj,
+            Documento::Cns(_) => TipoDocumento::Cns,
+            Documento::Pis(_) => TipoDocumento::Pis,
+            Documento::TituloEleitor(_) => TipoDocumento::TituloEleitor,
+            Documento::Cnh(_) => TipoDocumento::Cnh,
+            Documento::Renavam(_) => TipoDocumento::Renavam,
+            Documento::Rg(_) => TipoDocumento::Rg,
+            Documento::Ie(_) => TipoDocumento::Ie,
+            Documento::CodigoBarrasGs1(_) => TipoDocumento::CodigoBarrasGs1,
+            Documento::CartaoCredito(_) => TipoDocumento::CartaoCredito,
+        }
+    }
+
+    /// Número na forma canônica; a Inscrição Estadual é retornada sem a UF
+    pub fn as_str(&self) -> &str {
+        match self {
+            Documento::Cpf(documento) => documento.as_str(),
+            Documento::Cnpj(documento) => documento.as_str(),
+            Documento::Cns(documento) => documento.as_str(),
+            Documento::Pis(documento) => documento.as_str(),
+            Documento::TituloEleitor(documento) => documento.as_str(),
+            Documento::Cnh(documento) => documento.as_str(),
+            Documento::Renavam(documento) => documento.as_str(),
+            Documento::Rg(documento) => documento.as_str(),
+            Documento::Ie(documento) => documento.as_str(),
+            Documento::CodigoBarrasGs1(documento) => documento.as_str(),
+            Documento::CartaoCredito(documento) => documento.as_str(),
+        }
+    }
+
+    fn como_formatar(&self) -> &dyn Formatar {
+        match self {
+            Documento::Cpf(documento) => documento,
+            Documento::Cnpj(documento) => documento,
+            Documento::Cns(documento) => documento,
+            Documento::Pis(documento) => documento,
+            Documento::TituloEleitor(documento) => documento,
+            Documento::Cnh(documento) => documento,
+            Documento::Renavam(documento) => documento,
+            Documento::Rg(documento) => documento,
+            Documento::Ie(documento) => documento,
+            Documento::CodigoBarrasGs1(documento) => documento,
+            Documento::CartaoCredito(documento) => documento,
+        }
+    }
+
+    /// Retorna todos os tipos para os quais o número é válido, do mais provável para o menos provável
+    /// Um número com 11 dígitos pode ser ao mesmo tempo um CPF, um Pis e uma CNH válidos; nesse caso os três são retornados
+    /// Vêm primeiro os tipos cuja máscara coincide com o número informado, depois os de formato mais restrito; o empate segue `TipoDocumento::TODOS`
+    /// A Inscrição Estadual só é detectada quando precedida da UF, como em `SP 110.042.490.114`
+    pub fn detectar(numero: &str) -> Vec<Documento> {
+        let mut candidatos: Vec<Documento> = TipoDocumento::TODOS
+            .into_iter()
+            .filter_map(|tipo| Documento::try_from((tipo, numero)).ok())
+            .collect();
+        let mascara = |documento: &Documento| {
+            let formatado = documento.formatar();
+            formatado == numero.trim() && formatado != documento.canonico()
+        };
+        candidatos.sort_by_key(|documento| (!mascara(documento), documento.tipo().restricao()));
+        candidatos
+    }
+}
+
+/// Valida o número com o algoritmo do tipo informado
+impl TryFrom<(TipoDocumento, &str)> for Documento {
+    type Error = ErroValidacao;
+
+    fn try_from((tipo, numero): (TipoDocumento, &str)) -> Result<Self, Self::Error> {
+        Ok(match tipo {
+            TipoDocumento::Cpf => Documento::Cpf(CpfBuf::try_from(numero)?),
+            TipoDocumento::Cnpj => Documento::Cnpj(CnpjBuf::try_from(numero)?),
+            TipoDocumento::Cns => Documento::Cns(CnsBuf::try_from(numero)?),
+            TipoDocumento::Pis => Documento::Pis(PisBuf::try_from(numero)?),
+            TipoDocumento::TituloEleitor => Documento::TituloEleitor(TituloEleitorBuf::try_from(numero)?),
+            TipoDocumento::Cnh => Documento::Cnh(CnhBuf::try_from(numero)?),
+            TipoDocumento::Renavam => Documento::Renavam(RenavamBuf::try_from(numero)?),
+            TipoDocumento::Rg => Documento::Rg(RgBuf::try_from(numero)?),
+            TipoDocumento::Ie => Documento::Ie(IeBuf::try_from(numero)?),
+            TipoDocumento::CodigoBarrasGs1 => Documento::CodigoBarrasGs1(CodigoBarrasGs1Buf::try_from(numero)?),
+            TipoDocumento::CartaoCredito => Documento::CartaoCredito(CartaoCreditoBuf::try_from(numero)?),
+        })
+    }
+}
+
+/// Separa o identificador do tipo e o número, `cpf:255.248.930-33` ou `ie:SP 110.042.490.114`
+fn separar_tipo(s: &str) -> Result<(TipoDocumento, &str), ErroValidacao> {
+    match s.split_once(':') {
+        Some((tipo, numero)) => Ok((tipo.parse()?, numero)),
+        None => Err(ErroValidacao::TipoInvalido {
+            encontrado: String::new(),
+        }),
+    }
+}
+
+/// O número deve vir precedido do identificador do tipo, como em `cpf:12345678909`; use `Documento::detectar` quando o tipo não for conhecido
+impl FromStr for Documento {
+    type Err = ErroValidacao;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Documento::try_from(separar_tipo(s)?)
+    }
+}
+
+/// Exibe o identificador do tipo seguido do número formatado, `cpf:255.248.930-33`, no mesmo formato aceito por `FromStr`
+impl fmt::Display for Documento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Documento::Ie(ie) => write!(f, "{}:{}", self.tipo(), ie),
+            _ => write!(f, "{}:{}", self.tipo(), self.formatar()),
+        }
+    }
+}
+
+impl Formatar for Documento {
+    fn canonico(&self) -> String {
+        self.como_formatar().canonico()
+    }
+
+    fn formatar(&self) -> String {
+        self.como_formatar().formatar()
+    }
+}
+
+/// Verifica números precedidos do identificador do tipo, como em `cpf:12345678909`
+impl Validador for Documento {
+    fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        let (tipo, numero) = separar_tipo(numero)?;
+        tipo.verificar_com(numero, politica)
+    }
+
+    fn validar(&self) -> bool {
+        match self {
+            Documento::Cpf(documento) => documento.validar(),
+            Documento::Cnpj(documento) => documento.validar(),
+            Documento::Cns(documento) => documento.validar(),
+            Documento::Pis(documento) => documento.validar(),
+            Documento::TituloEleitor(documento) => documento.validar(),
+            Documento::Cnh(documento) => documento.validar(),
+            Documento::Renavam(documento) => documento.validar(),
+            Documento::Rg(documento) => documento.validar(),
+            Documento::Ie(documento) => documento.validar(),
+            Documento::CodigoBarrasGs1(documento) => documento.validar(),
+            Documento::CartaoCredito(documento) => documento.validar(),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Define o tipo `ErroValidacao`, retornado por `Validador::verificar` e por todas as implementações de `TryFrom`
+//! Permite identificar a causa exata da falha sem comparar mensagens de texto
+//!```rust
+//! # use validador_br::erro::ErroValidacao;
+//! # use validador_br::types::Cpf;
+//! match Cpf::try_from("085.668.830-48") {
+//!     Err(ErroValidacao::DigitoVerificador { esperado, .. }) => assert_eq!(esperado, vec![4, 7]),
+//!     _ => unreachable!(),
+//! }
+//!```
+
+use std::error::Error;
+use std::fmt;
+
+use crate::funcoes::texto;
+use crate::types::Bandeira;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErroValidacao {
+    /// A quantidade de dígitos numéricos não corresponde ao tamanho do documento
+    QuantidadeDigitos {
+        minimo: usize,
+        maximo: usize,
+        encontrado: usize,
+    },
+    /// A bandeira do cartão não emite cartões com essa quantidade de dígitos, como um Amex com 16 dígitos
+    QuantidadeDigitosBandeira { bandeira: Bandeira, encontrado: usize },
+    /// O número contém um caractere que não é dígito nem separador `.` `-` `/` ou espaço
+    CaractereInvalido { caractere: char, posicao: usize },
+    /// Sequências repetidas como `111.111.111-11` passam no cálculo do dígito verificador, mas não são documentos reais
+    DigitosRepetidos,
+    /// O dígito verificador informado não corresponde ao calculado
+    DigitoVerificador {
+        esperado: Vec<u32>,
+        encontrado: Vec<u32>,
+    },
+    /// Não existe dígito verificador possível para a base informada, como em alguns Cns iniciados em 7, 8 ou 9
+    SemDigitoVerificador,
+    /// Os dígitos iniciais não correspondem a um prefixo permitido, como o código do estado na Inscrição Estadual
+    PrefixoInvalido { encontrado: String },
+    /// A sigla não corresponde a nenhuma Unidade da Federação
+    UfInvalida { encontrado: String },
+    /// Um campo do documento está fora dos valores permitidos, como o mês 13 na chave de acesso
+    CampoInvalido { campo: &'static str, encontrado: String },
+    /// O identificador não corresponde a nenhum tipo de documento, como em `xyz:12345678909`
+    TipoInvalido { encontrado: String },
+    /// O documento ainda não possui algoritmo de validação
+    NaoImplementado,
+}
+
+/// Atalho para `ErroValidacao::CampoInvalido`
+pub(crate) fn campo_invalido(campo: &'static str, encontrado: impl ToString) -> ErroValidacao {
+    ErroValidacao::CampoInvalido {
+        campo,
+        encontrado: encontrado.to_string(),
+    }
+}
+
+impl fmt::Display for ErroValidacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErroValidacao::QuantidadeDigitos {
+                minimo,
+                maximo,
+                encontrado,
+            } if minimo == maximo => write!(
+                f,
+                "Quantidade de dígitos inválida: esperado {minimo}, encontrado {encontrado}"
+            ),
+            ErroValidacao::QuantidadeDigitos {
+                minimo,
+                maximo,
+                encontrado,
+            } => write!(
+                f,
+                "Quantidade de dígitos inválida: esperado entre {minimo} e {maximo}, encontrado {encontrado}"
+            ),
+            ErroValidacao::QuantidadeDigitosBandeira { bandeira, encontrado } => write!(
+                f,
+                "Quantidade de dígitos inválida para a bandeira {bandeira}: encontrado {encontrado}"
+            ),
+            ErroValidacao::CaractereInvalido { caractere, posicao } => write!(
+                f,
+                "Caractere inválido '{caractere}' na posição {posicao}"
+            ),
+            ErroValidacao::DigitosRepetidos => {
+                write!(f, "Sequência de dígitos repetidos não é um documento válido")
+            }
+            ErroValidacao::DigitoVerificador {
+                esperado,
+                encontrado,
+            } => write!(
+                f,
+                "Dígito verificador inválido: esperado {}, encontrado {}",
+                texto(esperado),
+                texto(encontrado)
+            ),
+            ErroValidacao::SemDigitoVerificador => {
+                write!(f, "Não existe dígito verificador possível para este número")
+            }
+            ErroValidacao::PrefixoInvalido { encontrado } => {
+                write!(f, "Prefixo inválido '{encontrado}'")
+            }
+            ErroValidacao::UfInvalida { encontrado } => {
+                write!(f, "Unidade da Federação inválida '{encontrado}'")
+            }
+            ErroValidacao::CampoInvalido { campo, encontrado } => {
+                write!(f, "Valor inválido '{encontrado}' para o campo {campo}")
+            }
+            ErroValidacao::TipoInvalido { encontrado } => {
+                write!(f, "Tipo de documento inválido '{encontrado}'")
+            }
+            ErroValidacao::NaoImplementado => {
+                write!(f, "Validação ainda não implementada para este documento")
+            }
+        }
+    }
+}
+
+impl Error for ErroValidacao {}
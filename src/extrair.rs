@@ -0,0 +1,206 @@
+//! Localiza números de documentos em um texto livre, como o corpo de um e-mail
+//! Números formatados são reconhecidos pela máscara e retornados mesmo com o dígito verificador incorreto, indicando `valido = false`
+//! Números sem formatação só são retornados quando válidos, para evitar falsos positivos com telefones, datas e outros números
+//!```rust
+//! # use validador_br::extrair::extrair;
+//! # use validador_br::types::TipoDocumento;
+//! let texto = "Cliente 255.248.930-33, empresa 76553412000110, tel (11) 98765-4321 em 12/05/2023";
+//! let encontrados: Vec<_> = extrair(texto).collect();
+//! assert_eq!(encontrados.len(), 2);
+//! assert_eq!(encontrados[0].tipo, TipoDocumento::Cpf);
+//! assert_eq!(encontrados[0].texto, "255.248.930-33");
+//! assert_eq!(&texto[encontrados[1].inicio..encontrados[1].fim], "76553412000110");
+//!```
+
+use crate::types::{Bandeira, Documento, TipoDocumento};
+use crate::validador::PoliticaRepeticao;
+
+/// Número encontrado no texto
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ocorrencia<'a> {
+    /// Posição inicial, em bytes
+    pub inicio: usize,
+    /// Posição final, em bytes, exclusiva
+    pub fim: usize,
+    /// Trecho do texto, como foi escrito
+    pub texto: &'a str,
+    pub tipo: TipoDocumento,
+    /// Indica se o dígito verificador está correto
+    pub valido: bool,
+}
+
+impl Ocorrencia<'_> {
+    /// O documento na forma canônica, se for válido
+    pub fn documento(&self) -> Option<Documento> {
+        Documento::try_from((self.tipo, self.texto)).ok()
+    }
+}
+
+/// Máscaras reconhecidas, das mais longas para as mais curtas
+/// `#` representa um dígito e `X` um dígito ou letra maiúscula, usada no CNPJ alfanumérico
+const MASCARAS: [(TipoDocumento, &str); 11] = [
+    (TipoDocumento::CartaoCredito, "#### #### #### #### ###"),
+    (TipoDocumento::CartaoCredito, "#### #### #### ####"),
+    (TipoDocumento::CartaoCredito, "####-####-####-####"),
+    (TipoDocumento::Cnpj, "XX.XXX.XXX/XXXX-##"),
+    (TipoDocumento::Cns, "### #### #### ####"),
+    (TipoDocumento::CartaoCredito, "#### ###### #####"),
+    (TipoDocumento::CartaoCredito, "#### ###### ####"),
+    (TipoDocumento::Cpf, "###.###.###-##"),
+    (TipoDocumento::Pis, "###.#####.##-#"),
+    (TipoDocumento::TituloEleitor, "#### #### ####"),
+    (TipoDocumento::Rg, "##.###.###-#"),
+];
+
+/// Palavras que, logo antes de um número, indicam um telefone
+const PALAVRAS_TELEFONE: [&str; 5] = ["tel", "fone", "cel", "whats", "ramal"];
+
+/// Tipos procurados por padrão
+/// Inscrição Estadual, que depende da UF, e Renavam, CNH e códigos de barras, que não possuem formato próprio, ficam de fora
+/// Com um único dígito verificador e de 9 a 11 dígitos, um em cada 11 números quaisquer seria um Renavam válido; informe-os em `Extrator::tipos` para procurá-los
+pub const TIPOS_PADRAO: [TipoDocumento; 6] = [
+    TipoDocumento::Cpf,
+    TipoDocumento::Cnpj,
+    TipoDocumento::Cns,
+    TipoDocumento::Pis,
+    TipoDocumento::TituloEleitor,
+    TipoDocumento::CartaoCredito,
+];
+
+/// Percorre o texto retornando cada `Ocorrencia`, na ordem em que aparecem
+pub struct Extrator<'a> {
+    texto: &'a str,
+    posicao: usize,
+    tipos: Vec<TipoDocumento>,
+}
+
+/// Atalho para `Extrator::new(texto)`, com os `TIPOS_PADRAO`
+pub fn extrair(texto: &str) -> Extrator<'_> {
+    Extrator::new(texto)
+}
+
+impl<'a> Extrator<'a> {
+    pub fn new(texto: &'a str) -> Self {
+        Extrator {
+            texto,
+            posicao: 0,
+            tipos: TIPOS_PADRAO.to_vec(),
+        }
+    }
+
+    /// Restringe a busca aos tipos informados
+    /// Um número sem formatação válido para mais de um tipo é atribuído ao primeiro, na ordem de `TipoDocumento::TODOS`
+    pub fn tipos(mut self, tipos: &[TipoDocumento]) -> Self {
+        self.tipos = tipos.to_vec();
+        self
+    }
+
+    fn ocorrencia(&self, inicio: usize, fim: usize, tipo: TipoDocumento) -> Ocorrencia<'a> {
+        let texto = &self.texto[inicio..fim];
+        Ocorrencia {
+            inicio,
+            fim,
+            texto,
+            tipo,
+            valido: tipo.verificar_com(texto, PoliticaRepeticao::default()).is_ok(),
+        }
+    }
+
+    /// Tenta cada máscara a partir do início de uma palavra
+    fn formatado(&self, inicio: usize) -> Option<Ocorrencia<'a>> {
+        let bytes = self.texto.as_bytes();
+        MASCARAS
+            .iter()
+            .filter(|(tipo, _)| self.tipos.contains(tipo))
+            .find(|(_, mascara)| {
+                let fim = inicio + mascara.len();
+                fim <= bytes.len()
+                    && mascara.bytes().zip(&bytes[inicio..fim]).all(|(m, &c)| match m {
+                        b'#' => c.is_ascii_digit(),
+                        b'X' => c.is_ascii_digit() || c.is_ascii_uppercase(),
+                        m => m == c,
+                    })
+                    && fim_de_numero(bytes, fim)
+            })
+            .map(|(tipo, mascara)| self.ocorrencia(inicio, inicio + mascara.len(), *tipo))
+    }
+
+    /// Sequência de dígitos sem formatação, retornada somente se for válida para um dos tipos
+    fn sem_formatacao(&self, inicio: usize, fim: usize) -> Option<Ocorrencia<'a>> {
+        let bytes = self.texto.as_bytes();
+        if !fim_de_numero(bytes, fim) || telefone(&self.texto[..inicio]) {
+            return None;
+        }
+        let numero = &self.texto[inicio..fim];
+        TipoDocumento::TODOS
+            .into_iter()
+            .filter(|tipo| self.tipos.contains(tipo))
+            // cartões sem formatação precisam ser de uma bandeira conhecida, para não serem confundidos com datas e horários longos
+            .filter(|tipo| *tipo != TipoDocumento::CartaoCredito || Bandeira::detectar(numero).is_some())
+            .find(|tipo| tipo.verificar_com(numero, PoliticaRepeticao::default()).is_ok())
+            .map(|tipo| self.ocorrencia(inicio, fim, tipo))
+    }
+}
+
+impl<'a> Iterator for Extrator<'a> {
+    type Item = Ocorrencia<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.texto.as_bytes();
+        while self.posicao < bytes.len() {
+            let inicio = self.posicao;
+            if !bytes[inicio].is_ascii_alphanumeric() || !inicio_de_numero(bytes, inicio) {
+                self.posicao += 1;
+                continue;
+            }
+            if let Some(ocorrencia) = self.formatado(inicio) {
+                self.posicao = ocorrencia.fim;
+                return Some(ocorrencia);
+            }
+            let fim = fim_da_palavra(bytes, inicio);
+            self.posicao = fim;
+            if bytes[inicio..fim].iter().all(u8::is_ascii_digit) {
+                if let Some(ocorrencia) = self.sem_formatacao(inicio, fim) {
+                    return Some(ocorrencia);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn fim_da_palavra(bytes: &[u8], inicio: usize) -> usize {
+    bytes[inicio..]
+        .iter()
+        .position(|c| !c.is_ascii_alphanumeric())
+        .map_or(bytes.len(), |tamanho| inicio + tamanho)
+}
+
+/// `.` `-` ou `/` entre dois caracteres alfanuméricos indicam que o número continua, como em datas e telefones
+fn separador_interno(bytes: &[u8], posicao: usize) -> bool {
+    matches!(bytes.get(posicao), Some(b'.' | b'-' | b'/'))
+        && posicao > 0
+        && bytes[posicao - 1].is_ascii_alphanumeric()
+        && bytes.get(posicao + 1).is_some_and(u8::is_ascii_alphanumeric)
+}
+
+fn inicio_de_numero(bytes: &[u8], inicio: usize) -> bool {
+    inicio == 0 || !(bytes[inicio - 1].is_ascii_alphanumeric() || separador_interno(bytes, inicio - 1))
+}
+
+fn fim_de_numero(bytes: &[u8], fim: usize) -> bool {
+    !(bytes.get(fim).is_some_and(u8::is_ascii_alphanumeric) || separador_interno(bytes, fim))
+}
+
+/// Números precedidos de `+`, `(..)` ou de palavras como `tel` e `celular`
+fn telefone(antes: &str) -> bool {
+    let antes = antes.trim_end();
+    if antes.ends_with(['+', ')']) {
+        return true;
+    }
+    let inicio = antes.char_indices().rev().nth(19).map_or(0, |(posicao, _)| posicao);
+    antes[inicio..]
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|palavra| PALAVRAS_TELEFONE.iter().any(|telefone| palavra.starts_with(telefone)))
+}
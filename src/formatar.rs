@@ -0,0 +1,255 @@
+//! Formatação dos documentos com a máscara oficial e forma canônica, somente com os dígitos
+//! Números cuja quantidade de dígitos não corresponde à máscara são retornados na forma canônica
+//!```rust
+//! # use validador_br::formatar::Formatar;
+//! # use validador_br::types::{Cpf, Cnpj};
+//! assert_eq!(Cpf("25524893033").formatar(), "255.248.930-33");
+//! assert_eq!(Cpf("255.248.930-33").canonico(), "25524893033");
+//! assert_eq!(Cnpj("76553412000110").formatar(), "76.553.412/0001-10");
+//!```
+
+use crate::funcoes::{aplicar_mascara, somente_alfanumericos, somente_digitos};
+use crate::types::{Arrecadacao, Boleto, CartaoCredito, ChaveAcesso, Cnh, Cnpj, Cns, CodigoBarrasGs1,
+    Cpf, Ie, Pis, Renavam, Rg, TipoCns, TituloEleitor, Uf,
+};
+use crate::validador_ie::{is_isento, IeUf, ISENTO};
+
+pub trait Formatar {
+    /// Somente os dígitos, sem pontuação ou espaços
+    fn canonico(&self) -> String;
+
+    /// Aplica a máscara oficial do documento
+    fn formatar(&self) -> String;
+}
+
+/// 255.248.930-33 => "25524893033"
+fn digitos(numero: &str) -> String {
+    somente_digitos(numero, numero.len())
+        .iter()
+        .map(|d| d.to_string())
+        .collect()
+}
+
+impl Formatar for Cpf<'_> {
+    fn canonico(&self) -> String {
+        digitos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        aplicar_mascara(&self.canonico(), "###.###.###-##")
+    }
+}
+
+/// Letras do CNPJ alfanumérico são mantidas, em maiúsculas
+impl Formatar for Cnpj<'_> {
+    fn canonico(&self) -> String {
+        somente_alfanumericos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        aplicar_mascara(&self.canonico(), "##.###.###/####-##")
+    }
+}
+
+/// Grupos de quatro dígitos
+impl Formatar for CartaoCredito<'_> {
+    fn canonico(&self) -> String {
+        digitos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        self.canonico()
+            .as_bytes()
+            .chunks(4)
+            .map(|grupo| String::from_utf8_lossy(grupo).into_owned())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Formatar for TituloEleitor<'_> {
+    fn canonico(&self) -> String {
+        digitos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        aplicar_mascara(&self.canonico(), "#### #### ####")
+    }
+}
+
+/// A CNH não possui máscara oficial
+impl Formatar for Cnh<'_> {
+    fn canonico(&self) -> String {
+        digitos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        self.canonico()
+    }
+}
+
+/// O Renavam não possui máscara oficial; a forma canônica tem 11 dígitos, completados com zeros à esquerda
+impl Formatar for Renavam<'_> {
+    fn canonico(&self) -> String {
+        format!("{:0>11}", digitos(self.0))
+    }
+
+    fn formatar(&self) -> String {
+        self.canonico()
+    }
+}
+
+impl Formatar for Rg<'_> {
+    fn canonico(&self) -> String {
+        digitos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        aplicar_mascara(&self.canonico(), "##.###.###-#")
+    }
+}
+
+impl Formatar for Cns<'_> {
+    fn canonico(&self) -> String {
+        digitos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        aplicar_mascara(&self.canonico(), "### #### #### ####")
+    }
+}
+
+impl Formatar for TipoCns<'_> {
+    fn canonico(&self) -> String {
+        match self {
+            TipoCns::Cns(value) => value.canonico(),
+            TipoCns::Provisorio(value) => value.canonico(),
+        }
+    }
+
+    fn formatar(&self) -> String {
+        match self {
+            TipoCns::Cns(value) => value.formatar(),
+            TipoCns::Provisorio(value) => value.formatar(),
+        }
+    }
+}
+
+/// O código de barras é impresso sem pontuação
+impl Formatar for CodigoBarrasGs1<'_> {
+    fn canonico(&self) -> String {
+        digitos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        self.canonico()
+    }
+}
+
+/// A linha digitável é separada em campos; o código de barras é impresso sem pontuação
+impl Formatar for Boleto<'_> {
+    fn canonico(&self) -> String {
+        digitos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        aplicar_mascara(&self.canonico(), "#####.##### #####.###### #####.###### # ##############")
+    }
+}
+
+/// Blocos da linha digitável separados do dígito verificador; o código de barras é impresso sem pontuação
+impl Formatar for Arrecadacao<'_> {
+    fn canonico(&self) -> String {
+        digitos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        aplicar_mascara(&self.canonico(), "###########-# ###########-# ###########-# ###########-#")
+    }
+}
+
+/// Grupos de quatro caracteres, como impresso no DANFE, mantendo as letras do CNPJ alfanumérico
+impl Formatar for ChaveAcesso<'_> {
+    fn canonico(&self) -> String {
+        somente_alfanumericos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        aplicar_mascara(&self.canonico(), "#### #### #### #### #### #### #### #### #### #### ####")
+    }
+}
+
+impl Formatar for Pis<'_> {
+    fn canonico(&self) -> String {
+        digitos(self.0)
+    }
+
+    fn formatar(&self) -> String {
+        aplicar_mascara(&self.canonico(), "###.#####.##-#")
+    }
+}
+
+/// Máscara usual da Inscrição Estadual de cada UF, de acordo com a quantidade de dígitos
+fn mascara_ie(uf: Uf, tamanho: usize) -> Option<&'static str> {
+    let mascara = match (uf, tamanho) {
+        (Uf::AC, 13) => "##.###.###/###-##",
+        (Uf::AL, 9) | (Uf::AP, 9) | (Uf::MA, 9) | (Uf::PI, 9) => "#########",
+        (Uf::AM, 9) | (Uf::GO, 9) => "##.###.###-#",
+        (Uf::BA, 8) => "######-##",
+        (Uf::BA, 9) => "#######-##",
+        (Uf::CE, 9) | (Uf::MS, 9) | (Uf::PB, 9) | (Uf::RR, 9) | (Uf::SE, 9) | (Uf::TO, 9) => "########-#",
+        (Uf::DF, 13) => "###########-##",
+        (Uf::ES, 9) => "###.###.##-#",
+        (Uf::MT, 11) => "##########-#",
+        (Uf::MG, 13) => "###.###.###/####",
+        (Uf::PA, 9) => "##-######-#",
+        (Uf::PR, 10) => "########-##",
+        (Uf::PE, 9) => "#######-##",
+        (Uf::PE, 14) => "##.#.###.#######-#",
+        (Uf::RJ, 8) => "##.###.##-#",
+        (Uf::RN, 9) => "##.###.###-#",
+        (Uf::RN, 10) => "##.#.###.###-#",
+        (Uf::RS, 10) => "###/#######",
+        (Uf::RO, 9) => "###.#####-#",
+        (Uf::RO, 14) => "#############-#",
+        (Uf::SC, 9) => "###.###.###",
+        (Uf::SP, 12) => "###.###.###.###",
+        (Uf::TO, 11) => "###########",
+        _ => return None,
+    };
+    Some(mascara)
+}
+
+/// O produtor rural de São Paulo mantém a letra `P` em ambas as formas; `ISENTO` é mantido como está
+impl Formatar for IeUf<'_> {
+    fn canonico(&self) -> String {
+        if is_isento(self.1) {
+            return ISENTO.to_string();
+        }
+        match self.1.trim_start().strip_prefix(['P', 'p']) {
+            Some(rural) if self.0 == Uf::SP => format!("P{}", digitos(rural)),
+            _ => digitos(self.1),
+        }
+    }
+
+    fn formatar(&self) -> String {
+        let canonico = self.canonico();
+        if let Some(rural) = canonico.strip_prefix('P') {
+            return format!("P-{}", aplicar_mascara(rural, "########.#/###"));
+        }
+        match mascara_ie(self.0, canonico.len()) {
+            Some(mascara) => aplicar_mascara(&canonico, mascara),
+            None => canonico,
+        }
+    }
+}
+
+impl Formatar for Ie<'_> {
+    fn canonico(&self) -> String {
+        self.0.canonico()
+    }
+
+    fn formatar(&self) -> String {
+        self.0.formatar()
+    }
+}
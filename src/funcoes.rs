@@ -1,5 +1,10 @@
 //! Define métodos que são usados com frequência pelos algoritmos de verificação, como *cálculo do dígito verificador*, *cálculo usando mod11*, `somente_digitos`
 
+use crate::erro::ErroValidacao;
+
+/// Caracteres de formatação aceitos entre os dígitos de um documento
+pub const SEPARADORES: [char; 4] = ['.', '-', '/', ' '];
+
 /// Define algumas funções básicas como `módulo_11`
 pub fn mod_11(value: u32) -> usize {
     match value % 11 {
@@ -23,14 +28,13 @@ pub fn onze_menos_mod11(value: u32) -> u32 {
 /// Por exemplo:
 /// * CPF os multiplicadores são sempre (10..=2) sendo dv1 função dos dígitos [0..9] e dv2 função dos dígitos [1..10]
 /// * IE os multiplicadores variam de estado para estado sendo dv1 = os dígitos [0..n-1] e dv2 = os dígitos [0..n]
-
+///
 /// Definimos um método genérico para calcular o dígito verificador de um doc, recebendo como parâmetro um vetor com os multiplicadores
 /// Este método **não** calculará mod 11 automaticamente, devendo ser passada por parâmetro uma função ou closure para realizar a verificação
 ///```rust
 /// # use validador_br::funcoes::calc_digito;
 ///assert!(calc_digito(vec![9, 5, 8, 7], vec![2, 3, 4, 5], |x| x % 10) == 0);
 ///```
-
 pub fn calc_digito(
     digitos: Vec<u32>,
     multiplicadores: Vec<u32>,
@@ -69,6 +73,16 @@ pub fn somente_digitos(doc: &str, n: usize) -> Vec<u32> {
         .collect()
 }
 
+/// Junta os dígitos em uma string, \[0,1,9\] => `019`
+pub(crate) fn texto(digitos: &[u32]) -> String {
+    digitos.iter().map(|d| d.to_string()).collect()
+}
+
+/// Lê os dígitos como um número decimal, \[0,1,9\] => `19`
+pub(crate) fn numero(digitos: &[u32]) -> u64 {
+    digitos.iter().fold(0, |numero, &digito| numero * 10 + digito as u64)
+}
+
 pub fn completa_esquerda(numero: &mut Vec<u32>, n: usize) {
     if numero.len() < n {
         for _ in 0..(n - numero.len()) {
@@ -76,4 +90,106 @@ pub fn completa_esquerda(numero: &mut Vec<u32>, n: usize) {
         }
     };
     // println!("{:?}", numero);
+}
+
+/// Verifica se o número contém apenas dígitos e os separadores usuais `.` `-` `/` e espaço
+///```rust
+/// # use validador_br::funcoes::verificar_caracteres;
+///assert!(verificar_caracteres("085.668.830-47").is_ok());
+///assert!(verificar_caracteres("085.668.83O-47").is_err());
+///```
+pub fn verificar_caracteres(doc: &str) -> Result<(), ErroValidacao> {
+    match doc
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !(c.is_ascii_digit() || SEPARADORES.contains(c)))
+    {
+        Some((posicao, caractere)) => Err(ErroValidacao::CaractereInvalido { caractere, posicao }),
+        None => Ok(()),
+    }
+}
+
+/// Extrai os dígitos do número, verificando antecipadamente se a quantidade está entre `minimo` e `maximo`
+/// Evita que os algoritmos de verificação acessem posições inexistentes quando o número está incompleto
+///```rust
+/// # use validador_br::funcoes::verificar_quantidade;
+///assert_eq!(verificar_quantidade("1.23-0", 4, 4).unwrap(), vec![1, 2, 3, 0]);
+///assert!(verificar_quantidade("123", 11, 11).is_err());
+///```
+pub fn verificar_quantidade(doc: &str, minimo: usize, maximo: usize) -> Result<Vec<u32>, ErroValidacao> {
+    let digitos = somente_digitos(doc, doc.len());
+    if (minimo..=maximo).contains(&digitos.len()) {
+        Ok(digitos)
+    } else {
+        Err(ErroValidacao::QuantidadeDigitos {
+            minimo,
+            maximo,
+            encontrado: digitos.len(),
+        })
+    }
+}
+
+/// Como `verificar_caracteres`, aceitando também as letras usadas no CNPJ alfanumérico
+///```rust
+/// # use validador_br::funcoes::verificar_caracteres_alfanumericos;
+///assert!(verificar_caracteres_alfanumericos("12.ABC.345/01DE-35").is_ok());
+///assert!(verificar_caracteres_alfanumericos("12.ABC.345/01DE_35").is_err());
+///```
+pub fn verificar_caracteres_alfanumericos(doc: &str) -> Result<(), ErroValidacao> {
+    match doc
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !(c.is_ascii_alphanumeric() || SEPARADORES.contains(c)))
+    {
+        Some((posicao, caractere)) => Err(ErroValidacao::CaractereInvalido { caractere, posicao }),
+        None => Ok(()),
+    }
+}
+
+/// Dígitos e letras do número, com as letras em maiúsculas, `12.abc.345/01DE-35` => `12ABC34501DE35`
+pub fn somente_alfanumericos(doc: &str) -> String {
+    doc.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Extrai os valores de um número alfanumérico, verificando a quantidade de caracteres
+/// Cada caractere vale o seu código ASCII menos 48: os dígitos valem de 0 a 9 e as letras de `A` = 17 a `Z` = 42
+///```rust
+/// # use validador_br::funcoes::verificar_quantidade_alfanumericos;
+///assert_eq!(verificar_quantidade_alfanumericos("1.aZ", 3, 3).unwrap(), vec![1, 17, 42]);
+///```
+pub fn verificar_quantidade_alfanumericos(doc: &str, minimo: usize, maximo: usize) -> Result<Vec<u32>, ErroValidacao> {
+    let valores: Vec<u32> = somente_alfanumericos(doc).bytes().map(|c| (c - b'0') as u32).collect();
+    if (minimo..=maximo).contains(&valores.len()) {
+        Ok(valores)
+    } else {
+        Err(ErroValidacao::QuantidadeDigitos {
+            minimo,
+            maximo,
+            encontrado: valores.len(),
+        })
+    }
+}
+
+/// Aplica uma máscara, substituindo cada `#` pelo próximo caractere do número
+/// Se a quantidade de caracteres não corresponder à máscara, o número é retornado sem formatação
+///```rust
+/// # use validador_br::funcoes::aplicar_mascara;
+///assert_eq!(aplicar_mascara("25524893033", "###.###.###-##"), "255.248.930-33");
+///assert_eq!(aplicar_mascara("2552489303", "###.###.###-##"), "2552489303");
+///```
+pub fn aplicar_mascara(numero: &str, mascara: &str) -> String {
+    if numero.chars().count() != mascara.matches('#').count() {
+        return numero.to_string();
+    }
+    let mut caracteres = numero.chars();
+    mascara
+        .chars()
+        .map(|c| match c {
+            '#' => caracteres.next().unwrap_or(c),
+            c => c,
+        })
+        .collect()
 }
\ No newline at end of file
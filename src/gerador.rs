@@ -0,0 +1,231 @@
+//! Gera números de documentos aleatórios e válidos, para uso em testes e fixtures
+//! Disponível com a feature `gerador`. Recebe qualquer gerador do crate `rand`; use um `SeedableRng` para obter resultados reproduzíveis
+//!```rust
+//! # use rand::{rngs::StdRng, SeedableRng};
+//! # use validador_br::gerador::Gerador;
+//! # use validador_br::types::Cpf;
+//! # use validador_br::validador::Validador;
+//! let mut gerador = Gerador::new(StdRng::seed_from_u64(42)).formatado(true);
+//! let cpf = gerador.cpf();
+//! assert!(Cpf::is_valid(&cpf));
+//! assert_eq!(cpf.len(), 14);
+//!```
+
+use rand::Rng;
+
+use crate::erro::{campo_invalido, ErroValidacao};
+use crate::formatar::Formatar;
+use crate::gerador_dv::GeradorDv;
+use crate::owned::CnpjBuf;
+use crate::types::{CartaoCredito, Cnh, Cnpj, Cns, CodigoBarrasGs1, Cpf, Pis, RegiaoFiscal, Renavam,
+    Rg, TituloEleitor, Uf,
+};
+use crate::validador::Validador;
+use crate::validador_ie::{calcular_dv_ie, IeUf};
+
+pub struct Gerador<R: Rng> {
+    rng: R,
+    formatado: bool,
+}
+
+impl<R: Rng> Gerador<R> {
+    pub fn new(rng: R) -> Self {
+        Gerador {
+            rng,
+            formatado: false,
+        }
+    }
+
+    /// Define se os números gerados usam a máscara oficial, como `000.000.000-00`, ou apenas dígitos
+    pub fn formatado(mut self, formatado: bool) -> Self {
+        self.formatado = formatado;
+        self
+    }
+
+    fn digitos(&mut self, quantidade: usize) -> String {
+        (0..quantidade)
+            .map(|_| char::from(b'0' + self.rng.random_range(0..10u8)))
+            .collect()
+    }
+
+    fn alfanumericos(&mut self, quantidade: usize) -> String {
+        const CARACTERES: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        (0..quantidade)
+            .map(|_| char::from(CARACTERES[self.rng.random_range(0..CARACTERES.len())]))
+            .collect()
+    }
+
+    fn saida(&self, documento: impl Formatar) -> String {
+        if self.formatado {
+            documento.formatar()
+        } else {
+            documento.canonico()
+        }
+    }
+
+    /// Sorteia bases até que o número completo seja aceito pelo `Validador`, descartando sequências repetidas e bases sem dígito possível
+    fn gerar<T: GeradorDv + Validador>(&mut self, mut base: impl FnMut(&mut Self) -> String) -> String {
+        loop {
+            let base = base(self);
+            if let Ok(dv) = T::gerar_dv(&base) {
+                let numero = format!("{base}{}", dv.iter().map(|d| d.to_string()).collect::<String>());
+                if T::is_valid(&numero) {
+                    return numero;
+                }
+            }
+        }
+    }
+
+    pub fn cpf(&mut self) -> String {
+        let numero = self.gerar::<Cpf>(|g| g.digitos(9));
+        self.saida(Cpf(&numero))
+    }
+
+    /// CPF emitido na região fiscal informada, que define o 9º dígito
+    pub fn cpf_regiao(&mut self, regiao: RegiaoFiscal) -> String {
+        let numero = self.gerar::<Cpf>(|g| format!("{}{}", g.digitos(8), regiao.digito()));
+        self.saida(Cpf(&numero))
+    }
+
+    /// CNPJ da matriz, com ordem `0001`
+    pub fn cnpj(&mut self) -> String {
+        self.cnpj_ordem(1)
+    }
+
+    /// CNPJ com o número de ordem informado, `1` para a matriz e `2..=9999` para as filiais
+    /// Ordens fora desse intervalo retornam `ErroValidacao::CampoInvalido`
+    pub fn cnpj_filial(&mut self, ordem: u16) -> Result<String, ErroValidacao> {
+        if ordem == 0 || ordem > 9999 {
+            return Err(campo_invalido("ordem", ordem));
+        }
+        Ok(self.cnpj_ordem(ordem))
+    }
+
+    /// Sorteia raízes até que `CnpjBuf::from_raiz` aceite o CNPJ, descartando sequências repetidas
+    fn cnpj_ordem(&mut self, ordem: u16) -> String {
+        let ordem = format!("{ordem:04}");
+        loop {
+            let raiz = self.digitos(8);
+            if let Ok(cnpj) = CnpjBuf::from_raiz(&raiz, &ordem) {
+                return self.saida(cnpj);
+            }
+        }
+    }
+
+    /// CNPJ alfanumérico, com letras e dígitos sorteados na raiz e na ordem
+    pub fn cnpj_alfanumerico(&mut self) -> String {
+        let numero = self.gerar::<Cnpj>(|g| g.alfanumericos(12));
+        self.saida(Cnpj(&numero))
+    }
+
+    pub fn pis(&mut self) -> String {
+        let numero = self.gerar::<Pis>(|g| g.digitos(10));
+        self.saida(Pis(&numero))
+    }
+
+    /// Cartão definitivo, iniciado em 1 ou 2, ou provisório, iniciado em 7, 8 ou 9
+    pub fn cns(&mut self) -> String {
+        let numero = self.gerar::<Cns>(|g| match g.rng.random_range(0..5) {
+            0 => format!("1{}", g.digitos(10)),
+            1 => format!("2{}", g.digitos(10)),
+            primeiro => format!("{}{}", primeiro + 5, g.digitos(13)),
+        });
+        self.saida(Cns(&numero))
+    }
+
+    pub fn cnh(&mut self) -> String {
+        let numero = self.gerar::<Cnh>(|g| g.digitos(9));
+        self.saida(Cnh(&numero))
+    }
+
+    pub fn renavam(&mut self) -> String {
+        let numero = self.gerar::<Renavam>(|g| g.digitos(10));
+        self.saida(Renavam(&numero))
+    }
+
+    /// O código da UF (01 a 28) ocupa o 9º e o 10º dígitos
+    pub fn titulo_eleitor(&mut self) -> String {
+        let numero = self.gerar::<TituloEleitor>(|g| {
+            let uf = g.rng.random_range(1..=28);
+            format!("{}{:02}", g.digitos(8), uf)
+        });
+        self.saida(TituloEleitor(&numero))
+    }
+
+    pub fn rg(&mut self) -> String {
+        let numero = self.gerar::<Rg>(|g| g.digitos(8));
+        self.saida(Rg(&numero))
+    }
+
+    /// Cartão de 16 dígitos iniciado em 4
+    pub fn cartao_credito(&mut self) -> String {
+        let numero = self.gerar::<CartaoCredito>(|g| format!("4{}", g.digitos(14)));
+        self.saida(CartaoCredito(&numero))
+    }
+
+    /// GTIN-13 com o prefixo 789 atribuído ao Brasil
+    pub fn codigo_barras_gs1(&mut self) -> String {
+        let numero = self.gerar::<CodigoBarrasGs1>(|g| format!("789{}", g.digitos(9)));
+        self.saida(CodigoBarrasGs1(&numero))
+    }
+
+    /// Inscrição Estadual da UF informada, com os dígitos verificadores calculados por `calcular_dv_ie`
+    pub fn ie(&mut self, uf: Uf) -> String {
+        let modelo = modelo_ie(uf);
+        loop {
+            let base: String = modelo
+                .chars()
+                .filter(|c| *c != '?')
+                .map(|c| match c {
+                    '#' => char::from(b'0' + self.rng.random_range(0..10u8)),
+                    c => c,
+                })
+                .collect();
+            if let Ok(dv) = calcular_dv_ie(uf, &base) {
+                let mut base = base.chars();
+                let mut dv = dv.into_iter();
+                let numero: String = modelo
+                    .chars()
+                    .filter_map(|c| match c {
+                        '?' => dv.next().and_then(|d| char::from_digit(d, 10)),
+                        _ => base.next(),
+                    })
+                    .collect();
+                return self.saida(IeUf(uf, &numero));
+            }
+        }
+    }
+}
+
+/// `#` representa um dígito aleatório e `?` um dígito verificador
+fn modelo_ie(uf: Uf) -> &'static str {
+    match uf {
+        Uf::AC => "01#########??",
+        Uf::AL => "24######?",
+        Uf::AP => "03######?",
+        Uf::AM => "########?",
+        Uf::BA => "#######??",
+        Uf::CE => "########?",
+        Uf::DF => "07#########??",
+        Uf::ES => "########?",
+        Uf::GO => "10######?",
+        Uf::MA => "12######?",
+        Uf::MT => "##########?",
+        Uf::MS => "28######?",
+        Uf::MG => "###########??",
+        Uf::PA => "15######?",
+        Uf::PB => "########?",
+        Uf::PR => "########??",
+        Uf::PE => "#######??",
+        Uf::PI => "19######?",
+        Uf::RJ => "#######?",
+        Uf::RN => "20######?",
+        Uf::RS => "#########?",
+        Uf::RO => "#############?",
+        Uf::RR => "24######?",
+        Uf::SC => "########?",
+        Uf::SP => "########?##?",
+        Uf::SE => "########?",
+        Uf::TO => "########?",
+    }
+}
@@ -0,0 +1,298 @@
+//! Cálculo do dígito verificador a partir da base de um documento
+//! Os mesmos cálculos são usados pelo trait `Validador`, de modo que um número completado sempre passa na validação
+//!```rust
+//! # use validador_br::gerador_dv::GeradorDv;
+//! # use validador_br::types::{Cpf, Cnpj};
+//! assert_eq!(Cpf::gerar_dv("123456789").unwrap(), vec![0, 9]);
+//! assert_eq!(Cpf::completar("123.456.789").unwrap(), "123456789-09");
+//! assert_eq!(Cnpj::completar("76.553.412/0001").unwrap(), "765534120001-10");
+//!```
+
+use crate::chave_acesso::verificar_letras;
+use crate::erro::ErroValidacao;
+use crate::funcoes::{calc_digito, completa_esquerda, mod_11,
+    onze_menos_mod11, somente_alfanumericos, texto, verificar_caracteres,
+    verificar_caracteres_alfanumericos, verificar_quantidade, verificar_quantidade_alfanumericos,
+};
+use crate::titulo_eleitor::uf_titulo;
+use crate::types::{CartaoCredito, ChaveAcesso, Cnh, Cnpj, Cns, CodigoBarrasGs1, Cpf, Pis, Renavam, Rg,
+    TituloEleitor,
+};
+
+pub trait GeradorDv {
+    /// Quantidade mínima e máxima de dígitos da base, sem os dígitos verificadores
+    const TAMANHO_BASE: (usize, usize);
+
+    /// Calcula os dígitos verificadores a partir dos dígitos da base; fora de `TAMANHO_BASE` o erro é `QuantidadeDigitos`
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao>;
+
+    /// Calcula os dígitos verificadores de uma base formatada ou não, como `123.456.789`
+    fn gerar_dv(base: &str) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_caracteres(base)?;
+        let (minimo, maximo) = Self::TAMANHO_BASE;
+        let digitos = verificar_quantidade(base, minimo, maximo)?;
+        Self::calcular_dv(&digitos)
+    }
+
+    /// Completa a base com os dígitos verificadores, `123456789` => `123456789-09`
+    fn completar(base: &str) -> Result<String, ErroValidacao> {
+        let dv = Self::gerar_dv(base)?;
+        let (_, maximo) = Self::TAMANHO_BASE;
+        let digitos = verificar_quantidade(base, 0, maximo)?;
+        Ok(format!("{}-{}", texto(&digitos), texto(&dv)))
+    }
+}
+
+/// Confere o tamanho da base recebida diretamente por `calcular_dv`, que não passa por `gerar_dv`
+fn verificar_base(base: &[u32], (minimo, maximo): (usize, usize)) -> Result<(), ErroValidacao> {
+    if (minimo..=maximo).contains(&base.len()) {
+        Ok(())
+    } else {
+        Err(ErroValidacao::QuantidadeDigitos {
+            minimo,
+            maximo,
+            encontrado: base.len(),
+        })
+    }
+}
+
+fn decima(x: u32) -> u32 {
+    mod_11(10 * x) as u32
+}
+
+impl GeradorDv for Cpf<'_> {
+    const TAMANHO_BASE: (usize, usize) = (9, 9);
+
+    /// dv1 é função dos dígitos \[0..9\] e dv2 dos dígitos \[1..10\], ambos com os multiplicadores 10..=2
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        let multiplicadores = vec![10, 9, 8, 7, 6, 5, 4, 3, 2];
+        let dv1 = calc_digito(base.to_vec(), multiplicadores.clone(), decima);
+        let mut part2 = base[1..].to_vec();
+        part2.push(dv1);
+        let dv2 = calc_digito(part2, multiplicadores, decima);
+        Ok(vec![dv1, dv2])
+    }
+}
+
+/// No CNPJ alfanumérico cada caractere vale o código ASCII menos 48, com os mesmos multiplicadores
+impl GeradorDv for Cnpj<'_> {
+    const TAMANHO_BASE: (usize, usize) = (12, 12);
+
+    fn gerar_dv(base: &str) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_caracteres_alfanumericos(base)?;
+        let (minimo, maximo) = Self::TAMANHO_BASE;
+        let valores = verificar_quantidade_alfanumericos(base, minimo, maximo)?;
+        Self::calcular_dv(&valores)
+    }
+
+    fn completar(base: &str) -> Result<String, ErroValidacao> {
+        let dv = Self::gerar_dv(base)?;
+        Ok(format!("{}-{}", somente_alfanumericos(base), texto(&dv)))
+    }
+
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        let multiplicadores1 = vec![5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+        let multiplicadores2 = vec![6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+        let dv1 = calc_digito(base.to_vec(), multiplicadores1, decima);
+        let mut part2 = base.to_vec();
+        part2.push(dv1);
+        let dv2 = calc_digito(part2, multiplicadores2, decima);
+        Ok(vec![dv1, dv2])
+    }
+}
+
+/// Algoritmo de Luhn
+impl GeradorDv for CartaoCredito<'_> {
+    const TAMANHO_BASE: (usize, usize) = (11, 18);
+
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        // da direita para a esquerda, a partir do primeiro dígito da base, os dígitos são alternadamente dobrados
+        let soma: u32 = base
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(index, &digit)| {
+                if index % 2 == 0 {
+                    digit / 5 + (2 * digit) % 10
+                } else {
+                    digit
+                }
+            })
+            .sum();
+        Ok(vec![(10 - soma % 10) % 10])
+    }
+}
+
+impl GeradorDv for TituloEleitor<'_> {
+    const TAMANHO_BASE: (usize, usize) = (10, 10);
+
+    /// dv1 é função do número sequencial \[0..8\] e dv2 do código da UF \[8..10\] seguido do dv1
+    /// O código da UF deve estar entre 01 e 28; em SP e MG o resto 0 resulta no dígito 1
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        let uf = uf_titulo(base)?;
+        let digito = |soma: u32| match soma % 11 {
+            10 => 0,
+            0 if uf.resto_zero_vale_um() => 1,
+            resto => resto,
+        };
+        let multiplicadores1 = vec![2, 3, 4, 5, 6, 7, 8, 9];
+        let multiplicadores2 = vec![7, 8, 9];
+        let dv1 = digito(calc_digito(base[0..8].to_vec(), multiplicadores1, |x| x));
+        let mut part2 = base[8..10].to_vec();
+        part2.push(dv1);
+        let dv2 = digito(calc_digito(part2, multiplicadores2, |x| x));
+        Ok(vec![dv1, dv2])
+    }
+}
+
+impl GeradorDv for Cnh<'_> {
+    const TAMANHO_BASE: (usize, usize) = (9, 9);
+
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        let multiplicadores1 = vec![9, 8, 7, 6, 5, 4, 3, 2, 1];
+        let multiplicadores2 = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        let mut verifica1 = calc_digito(base.to_vec(), multiplicadores1, |x| x);
+        let delta: u32 = if verifica1 % 11 == 10 { 2 } else { 0 };
+        verifica1 = mod_11(verifica1) as u32;
+
+        let verificador_final = |soma: u32| {
+            let mod11 = soma % 11;
+            let res = match mod11.checked_sub(delta) {
+                Some(x) => x,
+                None => 11 + mod11 - delta,
+            };
+            if res > 9 {
+                0
+            } else {
+                res
+            }
+        };
+
+        let mut verifica2 = calc_digito(base.to_vec(), multiplicadores2, |x| x);
+        verifica2 = verificador_final(verifica2);
+
+        Ok(vec![verifica1, verifica2])
+    }
+}
+
+/// O Renavam antigo, com 8 dígitos na base, é completado com zeros à esquerda
+impl GeradorDv for Renavam<'_> {
+    const TAMANHO_BASE: (usize, usize) = (8, 10);
+
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        let multiplicadores1 = vec![3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+        let mut digitos = base.to_vec();
+        completa_esquerda(&mut digitos, 10);
+        Ok(vec![calc_digito(digitos, multiplicadores1, onze_menos_mod11)])
+    }
+}
+
+impl GeradorDv for Rg<'_> {
+    const TAMANHO_BASE: (usize, usize) = (8, 8);
+
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        let multiplicadores1 = vec![2, 3, 4, 5, 6, 7, 8, 9];
+        Ok(vec![calc_digito(base.to_vec(), multiplicadores1, onze_menos_mod11)])
+    }
+}
+
+/// * Cartões iniciados em 1 ou 2 derivam do Pis: a base tem 11 dígitos e são gerados 4 dígitos, `000` ou `001` seguido do dv
+/// * Cartões iniciados em 7, 8 ou 9 têm 14 dígitos na base; o dv completa a soma ponderada até um múltiplo de 11
+impl GeradorDv for Cns<'_> {
+    const TAMANHO_BASE: (usize, usize) = (11, 14);
+
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        match (base.first(), base.len()) {
+            (Some(1) | Some(2), 11) => {
+                let multiplicadores1 = vec![15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5];
+                let mut soma = calc_digito(base.to_vec(), multiplicadores1, |x| x);
+                let resto = soma % 11;
+                let calculo = 11 - resto;
+                let mut digito_anterior = 0;
+                let calculo = if calculo == 11 {
+                    0
+                } else if calculo == 10 {
+                    soma += 2;
+                    let resto = soma % 11;
+                    digito_anterior += 1;
+                    11 - resto
+                } else {
+                    calculo
+                };
+                Ok(vec![0, 0, digito_anterior, calculo])
+            }
+            (Some(7..=9), 14) => {
+                let multiplicadores1 = vec![15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2];
+                match calc_digito(base.to_vec(), multiplicadores1, |x| (11 - x % 11) % 11) {
+                    10 => Err(ErroValidacao::SemDigitoVerificador),
+                    calculo => Ok(vec![calculo]),
+                }
+            }
+            (Some(1 | 2 | 7..=9), encontrado) => Err(ErroValidacao::QuantidadeDigitos {
+                minimo: if base[0] < 7 { 11 } else { 14 },
+                maximo: if base[0] < 7 { 11 } else { 14 },
+                encontrado,
+            }),
+            _ => Err(ErroValidacao::PrefixoInvalido {
+                encontrado: texto(&base[..base.len().min(1)]),
+            }),
+        }
+    }
+}
+
+/// GTIN-8, GTIN-12 (UPC), GTIN-13 (EAN), GTIN-14 e SSCC-18, completados com zeros à esquerda
+impl GeradorDv for CodigoBarrasGs1<'_> {
+    const TAMANHO_BASE: (usize, usize) = (7, 17);
+
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        let multiplicadores1 = vec![3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3];
+        let mut digitos = base.to_vec();
+        completa_esquerda(&mut digitos, 17);
+        Ok(vec![calc_digito(digitos, multiplicadores1, |x| (10 - x % 10) % 10)])
+    }
+}
+
+/// Pesos de 2 a 9 da direita para a esquerda; os restos 0 e 1 resultam no dígito 0
+/// As letras do CNPJ alfanumérico do emitente valem o código ASCII menos 48, como no próprio CNPJ
+impl GeradorDv for ChaveAcesso<'_> {
+    const TAMANHO_BASE: (usize, usize) = (43, 43);
+
+    fn gerar_dv(base: &str) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_caracteres_alfanumericos(base)?;
+        let (minimo, maximo) = Self::TAMANHO_BASE;
+        let valores = verificar_quantidade_alfanumericos(base, minimo, maximo)?;
+        verificar_letras(base)?;
+        Self::calcular_dv(&valores)
+    }
+
+    fn completar(base: &str) -> Result<String, ErroValidacao> {
+        let dv = Self::gerar_dv(base)?;
+        Ok(format!("{}-{}", somente_alfanumericos(base), texto(&dv)))
+    }
+
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        let multiplicadores1 = (0..base.len()).rev().map(|index| 2 + index as u32 % 8).collect();
+        Ok(vec![calc_digito(base.to_vec(), multiplicadores1, onze_menos_mod11)])
+    }
+}
+
+impl GeradorDv for Pis<'_> {
+    const TAMANHO_BASE: (usize, usize) = (10, 10);
+
+    fn calcular_dv(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_base(base, Self::TAMANHO_BASE)?;
+        let multiplicadores1 = vec![3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+        Ok(vec![calc_digito(base.to_vec(), multiplicadores1, onze_menos_mod11)])
+    }
+}
@@ -1,6 +1,30 @@
 #![doc=include_str!("../readme.md")]
 pub mod types;
+pub mod erro;
 pub mod validador_ie;
+pub mod uf;
+pub mod regiao_fiscal;
+pub mod titulo_eleitor;
+pub mod cnpj;
+pub mod data;
+pub mod boleto;
+pub mod arrecadacao;
+pub mod chave_acesso;
+pub mod pix;
+pub mod br_code;
+pub mod bandeira;
 pub mod funcoes;    
+pub mod gerador_dv;
+pub mod formatar;
+pub mod owned;
+pub mod documento;
+pub mod extrair;
+pub mod mascarar;
 pub mod validador;
 pub mod parser;
+#[cfg(feature = "gerador")]
+pub mod gerador;
+#[cfg(feature = "serde")]
+pub mod serializacao;
+#[cfg(feature = "pseudonimizacao")]
+pub mod pseudonimizacao;
@@ -0,0 +1,216 @@
+//! Mascaramento de documentos para exibição e registro em logs, conforme a LGPD
+//! O CPF segue a convenção de mascaramento do governo federal, ocultando os três primeiros e os dois últimos dígitos; cartões mantêm apenas os quatro últimos
+//! A formatação do número é preservada, de modo que o atendimento ainda possa conferir parte do documento
+//!```rust
+//! # use validador_br::mascarar::{redigir, Mascarar, PadraoMascara};
+//! # use validador_br::types::{CartaoCredito, Cpf};
+//! assert_eq!(Cpf("25524893033").mascarar(), "***.248.930-**");
+//! assert_eq!(CartaoCredito("5312833845316765").mascarar(), "**** **** **** 6765");
+//! assert_eq!(Cpf("25524893033").mascarar_com(&PadraoMascara::Ultimos(2)), "***.***.***-33");
+//! assert_eq!(redigir("CPF 255.248.930-33 ou 25524893033"), "CPF ***.248.930-** ou ***248930**");
+//!```
+
+use std::collections::HashMap;
+
+use crate::extrair::{Extrator, TIPOS_PADRAO};
+use crate::formatar::Formatar;
+use crate::owned::{CartaoCreditoBuf, CnhBuf, CnpjBuf, CnsBuf, CodigoBarrasGs1Buf, CpfBuf, IeBuf,
+    PisBuf, RenavamBuf, RgBuf, TituloEleitorBuf,
+};
+use crate::types::{CartaoCredito, Cnh, Cnpj, Cns, CodigoBarrasGs1, Cpf, Documento, Ie, Pis,
+    Renavam, Rg, TipoCns, TipoDocumento, TituloEleitor,
+};
+use crate::validador_ie::IeUf;
+
+/// Caractere usado no lugar dos dígitos ocultos
+pub const OCULTO: char = '*';
+
+/// Define quais dígitos são ocultados
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PadraoMascara {
+    /// Máscara escrita sobre o número formatado: `#` mantém o dígito e `*` o oculta, como em `***.###.###-**`
+    /// Os demais caracteres servem apenas de referência; a formatação do número original é mantida
+    /// Se a quantidade de dígitos não corresponder à máscara, todos os dígitos são ocultados
+    Mascara(String),
+    /// Mantém somente os últimos dígitos
+    Ultimos(usize),
+    /// Oculta todos os dígitos
+    Total,
+}
+
+impl TipoDocumento {
+    /// Padrão usado por `Mascarar::mascarar` e por `redigir`
+    pub fn padrao_mascara(&self) -> PadraoMascara {
+        let mascara = match self {
+            TipoDocumento::Cpf => "***.###.###-**",
+            TipoDocumento::Cnpj => "**.###.###/####-**",
+            TipoDocumento::Pis => "***.#####.##-*",
+            TipoDocumento::Cns => "*** #### #### ****",
+            TipoDocumento::TituloEleitor => "**** #### ****",
+            TipoDocumento::Rg => "**.###.###-*",
+            TipoDocumento::CartaoCredito
+            | TipoDocumento::Cnh
+            | TipoDocumento::Renavam
+            | TipoDocumento::Ie
+            | TipoDocumento::CodigoBarrasGs1 => return PadraoMascara::Ultimos(4),
+        };
+        PadraoMascara::Mascara(mascara.to_string())
+    }
+}
+
+/// Oculta os dígitos e letras do texto de acordo com o padrão, mantendo pontuação e espaços
+///```rust
+/// # use validador_br::mascarar::{mascarar_texto, PadraoMascara};
+///let padrao = PadraoMascara::Mascara("***.###.###-**".to_string());
+///assert_eq!(mascarar_texto("255.248.930-33", &padrao), "***.248.930-**");
+///assert_eq!(mascarar_texto("25524893033", &padrao), "***248930**");
+///assert_eq!(mascarar_texto("2552489303", &padrao), "**********");
+///```
+pub fn mascarar_texto(texto: &str, padrao: &PadraoMascara) -> String {
+    let quantidade = texto.chars().filter(char::is_ascii_alphanumeric).count();
+    let ocultos: Vec<bool> = match padrao {
+        PadraoMascara::Mascara(mascara) => {
+            let posicoes: Vec<bool> = mascara
+                .chars()
+                .filter(|c| matches!(c, '#' | '*'))
+                .map(|c| c == '*')
+                .collect();
+            if posicoes.len() == quantidade {
+                posicoes
+            } else {
+                vec![true; quantidade]
+            }
+        }
+        PadraoMascara::Ultimos(visiveis) => (0..quantidade)
+            .map(|posicao| posicao + visiveis < quantidade)
+            .collect(),
+        PadraoMascara::Total => vec![true; quantidade],
+    };
+    let mut ocultos = ocultos.into_iter();
+    texto
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() && ocultos.next().unwrap_or(true) {
+                OCULTO
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+pub trait Mascarar: Formatar {
+    /// Padrão usado por `mascarar()`
+    fn padrao_mascara(&self) -> PadraoMascara;
+
+    /// O número formatado, com os dígitos ocultados pelo padrão do documento
+    fn mascarar(&self) -> String {
+        self.mascarar_com(&self.padrao_mascara())
+    }
+
+    /// O número formatado, com os dígitos ocultados pelo padrão informado
+    fn mascarar_com(&self, padrao: &PadraoMascara) -> String {
+        mascarar_texto(&self.formatar(), padrao)
+    }
+}
+
+macro_rules! mascarar {
+    ($($tipo:ty => $tipo_documento:ident),* $(,)?) => {
+        $(
+            impl Mascarar for $tipo {
+                fn padrao_mascara(&self) -> PadraoMascara {
+                    TipoDocumento::$tipo_documento.padrao_mascara()
+                }
+            }
+        )*
+    };
+}
+
+mascarar!(
+    Cpf<'_> => Cpf,
+    Cnpj<'_> => Cnpj,
+    CartaoCredito<'_> => CartaoCredito,
+    TituloEleitor<'_> => TituloEleitor,
+    Cnh<'_> => Cnh,
+    Renavam<'_> => Renavam,
+    Rg<'_> => Rg,
+    Cns<'_> => Cns,
+    TipoCns<'_> => Cns,
+    CodigoBarrasGs1<'_> => CodigoBarrasGs1,
+    Pis<'_> => Pis,
+    IeUf<'_> => Ie,
+    Ie<'_> => Ie,
+    CpfBuf => Cpf,
+    CnpjBuf => Cnpj,
+    CartaoCreditoBuf => CartaoCredito,
+    TituloEleitorBuf => TituloEleitor,
+    CnhBuf => Cnh,
+    RenavamBuf => Renavam,
+    RgBuf => Rg,
+    CnsBuf => Cns,
+    CodigoBarrasGs1Buf => CodigoBarrasGs1,
+    PisBuf => Pis,
+    IeBuf => Ie,
+);
+
+impl Mascarar for Documento {
+    fn padrao_mascara(&self) -> PadraoMascara {
+        self.tipo().padrao_mascara()
+    }
+}
+
+/// Oculta os documentos encontrados em um texto livre, localizados com `extrair`
+/// Todos os números formatados são ocultados, mesmo com o dígito verificador incorreto
+pub struct Redator {
+    tipos: Vec<TipoDocumento>,
+    padroes: HashMap<TipoDocumento, PadraoMascara>,
+}
+
+impl Default for Redator {
+    fn default() -> Self {
+        Redator {
+            tipos: TIPOS_PADRAO.to_vec(),
+            padroes: HashMap::new(),
+        }
+    }
+}
+
+impl Redator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restringe a busca aos tipos informados
+    pub fn tipos(mut self, tipos: &[TipoDocumento]) -> Self {
+        self.tipos = tipos.to_vec();
+        self
+    }
+
+    /// Substitui o padrão de mascaramento de um tipo
+    pub fn padrao(mut self, tipo: TipoDocumento, padrao: PadraoMascara) -> Self {
+        self.padroes.insert(tipo, padrao);
+        self
+    }
+
+    pub fn redigir(&self, texto: &str) -> String {
+        let mut resultado = String::with_capacity(texto.len());
+        let mut posicao = 0;
+        for ocorrencia in Extrator::new(texto).tipos(&self.tipos) {
+            let padrao = self
+                .padroes
+                .get(&ocorrencia.tipo)
+                .cloned()
+                .unwrap_or_else(|| ocorrencia.tipo.padrao_mascara());
+            resultado.push_str(&texto[posicao..ocorrencia.inicio]);
+            resultado.push_str(&mascarar_texto(ocorrencia.texto, &padrao));
+            posicao = ocorrencia.fim;
+        }
+        resultado.push_str(&texto[posicao..]);
+        resultado
+    }
+}
+
+/// Atalho para `Redator::new().redigir(texto)`
+pub fn redigir(texto: &str) -> String {
+    Redator::new().redigir(texto)
+}
@@ -0,0 +1,287 @@
+//! Versões dos documentos que possuem os próprios dados, sem o lifetime `'data`
+//! Armazenam somente os dígitos normalizados e podem ser guardadas em structs, enviadas entre threads ou usadas como chave de um `HashMap`
+//! São construídas apenas por meio de construtores que validam o número
+//!```rust
+//! # use std::collections::HashMap;
+//! # use validador_br::owned::CpfBuf;
+//! let cpf: CpfBuf = "255.248.930-33".parse().unwrap();
+//! assert_eq!(cpf.as_str(), "25524893033");
+//! assert_eq!(cpf, CpfBuf::try_from("25524893033").unwrap());
+//!
+//! let mut clientes = HashMap::new();
+//! clientes.insert(cpf, "Maria");
+//!```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::erro::ErroValidacao;
+use crate::formatar::Formatar;
+use crate::types::{CartaoCredito, Cnh, Cnpj, Cns, CodigoBarrasGs1, Cpf, Pis, Renavam, Rg,
+    TituloEleitor, Uf,
+};
+use crate::validador::{PoliticaRepeticao, Validador};
+use crate::validador_ie::{separar_uf, verificar_ie, IeUf};
+
+/// Implementações comuns a todos os documentos próprios: construção validada, `Display`, `Debug`, `Formatar` e `Validador`
+macro_rules! documento_proprio_comum {
+    ($nome:ident, $tipo:ident) => {
+        impl $nome {
+            /// Versão emprestada do documento, que compartilha os mesmos dígitos
+            pub fn as_documento(&self) -> $tipo<'_> {
+                $tipo(self.as_str())
+            }
+        }
+
+        impl TryFrom<&str> for $nome {
+            type Error = ErroValidacao;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                $tipo::try_from(value).map(|documento| Self::de_canonico(&documento.canonico()))
+            }
+        }
+
+        impl TryFrom<String> for $nome {
+            type Error = ErroValidacao;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Self::try_from(value.as_str())
+            }
+        }
+
+        impl TryFrom<&String> for $nome {
+            type Error = ErroValidacao;
+
+            fn try_from(value: &String) -> Result<Self, Self::Error> {
+                Self::try_from(value.as_str())
+            }
+        }
+
+        impl TryFrom<$tipo<'_>> for $nome {
+            type Error = ErroValidacao;
+
+            fn try_from(value: $tipo<'_>) -> Result<Self, Self::Error> {
+                Self::try_from(value.0)
+            }
+        }
+
+        impl FromStr for $nome {
+            type Err = ErroValidacao;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::try_from(s)
+            }
+        }
+
+        impl AsRef<str> for $nome {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        /// Exibe o documento formatado
+        impl fmt::Display for $nome {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.formatar())
+            }
+        }
+
+        impl fmt::Debug for $nome {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($nome)).field(&self.as_str()).finish()
+            }
+        }
+
+        impl Formatar for $nome {
+            fn canonico(&self) -> String {
+                self.as_str().to_string()
+            }
+
+            fn formatar(&self) -> String {
+                self.as_documento().formatar()
+            }
+        }
+
+        impl Validador for $nome {
+            fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+                $tipo::verificar_com(numero, politica)
+            }
+
+            fn validar(&self) -> bool {
+                self.as_documento().validar()
+            }
+        }
+    };
+}
+
+/// Documento de tamanho fixo, armazenado como `[u8; N]` com os dígitos em ASCII
+macro_rules! documento_proprio {
+    ($(#[$doc:meta])* $nome:ident, $tipo:ident, $tamanho:literal) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $nome([u8; $tamanho]);
+
+        impl $nome {
+            /// Somente os dígitos, sem formatação
+            pub fn as_str(&self) -> &str {
+                std::str::from_utf8(&self.0).expect("os dígitos são sempre ASCII")
+            }
+
+            /// Os dígitos como valores numéricos de 0 a 9
+            /// As letras do CNPJ alfanumérico valem o código ASCII menos 48, como no cálculo do dígito verificador
+            pub fn digitos(&self) -> [u8; $tamanho] {
+                self.0.map(|c| c - b'0')
+            }
+
+            fn de_canonico(canonico: &str) -> Self {
+                let mut bytes = [0u8; $tamanho];
+                bytes.copy_from_slice(canonico.as_bytes());
+                Self(bytes)
+            }
+        }
+
+        documento_proprio_comum!($nome, $tipo);
+    };
+}
+
+/// Documento de tamanho variável, armazenado como `Box<str>` com os dígitos
+macro_rules! documento_proprio_variavel {
+    ($(#[$doc:meta])* $nome:ident, $tipo:ident) => {
+        $(#[$doc])*
+        #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $nome(Box<str>);
+
+        impl $nome {
+            /// Somente os dígitos, sem formatação
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            fn de_canonico(canonico: &str) -> Self {
+                Self(canonico.into())
+            }
+        }
+
+        documento_proprio_comum!($nome, $tipo);
+    };
+}
+
+documento_proprio!(
+    /// Cadastro de Pessoa Física com os 11 dígitos
+    CpfBuf, Cpf, 11
+);
+documento_proprio!(
+    /// Cadastro de Pessoa Jurídica com os 14 caracteres, numérico ou alfanumérico
+    CnpjBuf, Cnpj, 14
+);
+documento_proprio!(
+    /// Título de Eleitor com os 12 dígitos
+    TituloEleitorBuf, TituloEleitor, 12
+);
+documento_proprio!(
+    /// Carteira Nacional de Habilitação com os 11 dígitos
+    CnhBuf, Cnh, 11
+);
+documento_proprio!(
+    /// Renavam com 11 dígitos, completado com zeros à esquerda
+    RenavamBuf, Renavam, 11
+);
+documento_proprio!(
+    /// Registro Geral com os 9 dígitos
+    RgBuf, Rg, 9
+);
+documento_proprio!(
+    /// Cartão Nacional de Saúde com os 15 dígitos
+    CnsBuf, Cns, 15
+);
+documento_proprio!(
+    /// Pis, Pasep ou Nit com os 11 dígitos
+    PisBuf, Pis, 11
+);
+documento_proprio_variavel!(
+    /// Cartão de Crédito, de 12 a 19 dígitos
+    CartaoCreditoBuf, CartaoCredito
+);
+documento_proprio_variavel!(
+    /// Código de Barras Gs1, de 8 a 18 dígitos, mantendo o tamanho original
+    CodigoBarrasGs1Buf, CodigoBarrasGs1
+);
+
+/// Inscrição Estadual acompanhada da UF, com o número na forma canônica
+/// O produtor rural de SP mantém a letra `P` e contribuintes isentos são representados por `ISENTO`
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct IeBuf {
+    uf: Uf,
+    numero: Box<str>,
+}
+
+impl IeBuf {
+    pub fn uf(&self) -> Uf {
+        self.uf
+    }
+
+    /// Número na forma canônica, sem a UF
+    pub fn as_str(&self) -> &str {
+        &self.numero
+    }
+
+    pub fn as_documento(&self) -> IeUf<'_> {
+        IeUf(self.uf, &self.numero)
+    }
+}
+
+impl TryFrom<(Uf, &str)> for IeBuf {
+    type Error = ErroValidacao;
+
+    fn try_from((uf, value): (Uf, &str)) -> Result<Self, Self::Error> {
+        verificar_ie(uf, value)?;
+        Ok(IeBuf {
+            uf,
+            numero: IeUf(uf, value).canonico().into(),
+        })
+    }
+}
+
+/// O número deve vir precedido da sigla da UF, como em `SP 110.042.490.114`
+impl TryFrom<&str> for IeBuf {
+    type Error = ErroValidacao;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(separar_uf(value)?)
+    }
+}
+
+impl FromStr for IeBuf {
+    type Err = ErroValidacao;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+/// Exibe a UF seguida do número formatado, `SP 110.042.490.114`
+impl fmt::Display for IeBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.uf, self.formatar())
+    }
+}
+
+impl Formatar for IeBuf {
+    fn canonico(&self) -> String {
+        self.numero.to_string()
+    }
+
+    fn formatar(&self) -> String {
+        self.as_documento().formatar()
+    }
+}
+
+impl Validador for IeBuf {
+    fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        IeUf::verificar_com(numero, politica)
+    }
+
+    fn validar(&self) -> bool {
+        self.as_documento().validar()
+    }
+}
@@ -1,29 +1,23 @@
+use crate::erro::ErroValidacao;
 use crate::validador::{Validador};
-use crate::types::{CartaoCredito, Cnh, Cnpj, Cpf, Cns, Pis, Renavam, Rg, 
-    TituloEleitor, CodigoBarrasGs1,
+use crate::types::{Arrecadacao, Boleto, CartaoCredito, ChaveAcesso, Cnh, Cnpj, Cpf, Cns, Ie, Pis,
+    Renavam, Rg, TituloEleitor, CodigoBarrasGs1, Uf,
 };
+use crate::validador_ie::{separar_uf, verificar_ie, IeUf};
 
 impl<'data> TryFrom<&'data str> for CartaoCredito<'data>{
-type Error = &'static str;
+type Error = ErroValidacao;
 
     fn try_from(value: &'data str) -> Result<Self, Self::Error> {
-        if Self::is_valid(value) {
-            Ok(Self(value))        
-        } else {
-            Err("Cartão de crédito inválido")
-        }
+        Self::verificar(value).map(|_| Self(value))
     }
 }
 
 impl<'data> TryFrom<&'data str> for Cpf<'data>{
-type Error = &'static str;
+type Error = ErroValidacao;
 
     fn try_from(value: &'data str) -> Result<Self, Self::Error> {
-        if Self::is_valid(value) {
-            Ok(Self(value))        
-        } else {
-            Err("Cpf inválido")
-        }
+        Self::verificar(value).map(|_| Self(value))
     }
 }
 
@@ -39,98 +33,116 @@ type Error = &'static str;
 // }
 // }
 
+impl<'data> TryFrom<&'data str> for Arrecadacao<'data>{
+type Error = ErroValidacao;
+
+    fn try_from(value: &'data str) -> Result<Self, Self::Error> {
+        Self::verificar(value).map(|_| Self(value))
+    }
+}
+
+impl<'data> TryFrom<&'data str> for Boleto<'data>{
+type Error = ErroValidacao;
+
+    fn try_from(value: &'data str) -> Result<Self, Self::Error> {
+        Self::verificar(value).map(|_| Self(value))
+    }
+}
+
+impl<'data> TryFrom<&'data str> for ChaveAcesso<'data>{
+type Error = ErroValidacao;
+
+    fn try_from(value: &'data str) -> Result<Self, Self::Error> {
+        Self::verificar(value).map(|_| Self(value))
+    }
+}
+
 impl<'data> TryFrom<&'data str> for Cnh<'data>{
-type Error = &'static str;
+type Error = ErroValidacao;
 
     fn try_from(value: &'data str) -> Result<Self, Self::Error> {
-        if Self::is_valid(value) {
-            Ok(Self(value))        
-        } else {
-            Err("Carteira de motorista inválida")
-        }
+        Self::verificar(value).map(|_| Self(value))
     }
 }
 
 impl<'data> TryFrom<&'data str> for Cnpj<'data>{
-type Error = &'static str;
+type Error = ErroValidacao;
 
     fn try_from(value: &'data str) -> Result<Self, Self::Error> {
-        if Self::is_valid(value) {
-            Ok(Self(value))        
-        } else {
-            Err("cnpj inválido")
-        }
+        Self::verificar(value).map(|_| Self(value))
     }
 }
 
 impl<'data> TryFrom<&'data str> for Cns<'data>{
-type Error = &'static str;
+type Error = ErroValidacao;
 
     fn try_from(value: &'data str) -> Result<Self, Self::Error> {
-        if Self::is_valid(value) {
-            Ok(Self(value))        
-        } else {
-            Err("Cartão de saúde inválido")
-        }
+        Self::verificar(value).map(|_| Self(value))
     }
 }
 
 impl<'data> TryFrom<&'data str> for Pis<'data>{
-type Error = &'static str;
+type Error = ErroValidacao;
 
     fn try_from(value: &'data str) -> Result<Self, Self::Error> {
-        if Self::is_valid(value) {
-            Ok(Self(value))        
-        } else {
-            Err("Número do Pis inválido")
-        }
+        Self::verificar(value).map(|_| Self(value))
     }
 }
 
 impl<'data> TryFrom<&'data str> for Renavam<'data>{
-type Error = &'static str;
+type Error = ErroValidacao;
 
     fn try_from(value: &'data str) -> Result<Self, Self::Error> {
-        if Self::is_valid(value) {
-            Ok(Self(value))        
-        } else {
-            Err("Número do Renavam inválido")
-        }
+        Self::verificar(value).map(|_| Self(value))
     }
 }
 
 impl<'data> TryFrom<&'data str> for Rg<'data>{
-type Error = &'static str;
+type Error = ErroValidacao;
 
     fn try_from(value: &'data str) -> Result<Self, Self::Error> {
-        if Self::is_valid(value) {
-            Ok(Self(value))        
-        } else {
-            Err("Não foi possível validar o número de identidade. Verifique a legislação do seu Estado")
-        }
+        Self::verificar(value).map(|_| Self(value))
     }
 }
 
 impl<'data> TryFrom<&'data str> for TituloEleitor<'data>{
-type Error = &'static str;
+type Error = ErroValidacao;
 
     fn try_from(value: &'data str) -> Result<Self, Self::Error> {
-        if Self::is_valid(value) {
-            Ok(Self(value))        
-        } else {
-            Err("Número do Título de Eleitor inválido")
-        }
+        Self::verificar(value).map(|_| Self(value))
     }
 }
 
 impl<'data> TryFrom<&'data str> for CodigoBarrasGs1<'data>{
-type Error = &'static str;
+type Error = ErroValidacao;
+
+    fn try_from(value: &'data str) -> Result<Self, Self::Error> {
+        Self::verificar(value).map(|_| Self(value))
+    }
+}
+
+impl<'data> TryFrom<(Uf, &'data str)> for IeUf<'data>{
+type Error = ErroValidacao;
+
+    fn try_from((uf, value): (Uf, &'data str)) -> Result<Self, Self::Error> {
+        verificar_ie(uf, value).map(|_| Self(uf, value))
+    }
+}
+
+/// O número deve vir precedido da sigla da UF, como em `SP 110.042.490.114`
+impl<'data> TryFrom<&'data str> for IeUf<'data>{
+type Error = ErroValidacao;
+
+    fn try_from(value: &'data str) -> Result<Self, Self::Error> {
+        let (uf, numero) = separar_uf(value)?;
+        Self::try_from((uf, numero))
+    }
+}
+
+impl<'data> TryFrom<&'data str> for Ie<'data>{
+type Error = ErroValidacao;
 
     fn try_from(value: &'data str) -> Result<Self, Self::Error> {
-        if Self::is_valid(value) {
-            Ok(Self(value))        
-        } else {
-            Err("Não foi possível validar o dígito verificador do código de barras")
-        }
+        IeUf::try_from(value).map(Self)
     }
 }
\ No newline at end of file
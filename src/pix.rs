@@ -0,0 +1,285 @@
+//! Chaves PIX: CPF, CNPJ, e-mail, telefone celular com `+55` e chave aleatória (EVP)
+//! O tipo é detectado pelo formato da chave, que é validada e normalizada na forma registrada no DICT
+//!```rust
+//! # use validador_br::types::{ChavePix, TipoChavePix};
+//! let chave = ChavePix::try_from("+55 (11) 98765-4321").unwrap();
+//! assert_eq!(chave.tipo(), TipoChavePix::Telefone);
+//! assert_eq!(chave.as_str(), "+5511987654321");
+//!
+//! assert_eq!(ChavePix::try_from("255.248.930-33").unwrap().as_str(), "25524893033");
+//! assert_eq!(ChavePix::try_from(" Fulano@Exemplo.com.br ").unwrap().as_str(), "fulano@exemplo.com.br");
+//! assert_eq!(
+//!     ChavePix::try_from("123E4567-E89B-42D3-A456-426614174000").unwrap().tipo(),
+//!     TipoChavePix::Aleatoria
+//! );
+//! assert!(ChavePix::try_from("fulano@exemplo").is_err());
+//!```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::erro::{campo_invalido, ErroValidacao};
+use crate::formatar::Formatar;
+use crate::funcoes::{aplicar_mascara, somente_alfanumericos};
+use crate::owned::{CnpjBuf, CpfBuf};
+use crate::types::{ChavePix, TipoChavePix};
+use crate::validador::{PoliticaRepeticao, Validador};
+
+/// Tamanho máximo do e-mail aceito pelo DICT
+pub const TAMANHO_MAXIMO_EMAIL: usize = 77;
+
+/// Caracteres aceitos antes do `@`, além de letras e dígitos
+const CARACTERES_EMAIL: &str = ".!#$%&'*+/=?^_`{|}~-";
+
+impl TipoChavePix {
+    pub const TODOS: [TipoChavePix; 5] = [
+        TipoChavePix::Cpf,
+        TipoChavePix::Cnpj,
+        TipoChavePix::Email,
+        TipoChavePix::Telefone,
+        TipoChavePix::Aleatoria,
+    ];
+
+    /// Nome do tipo no DICT
+    pub fn identificador(&self) -> &'static str {
+        match self {
+            TipoChavePix::Cpf => "CPF",
+            TipoChavePix::Cnpj => "CNPJ",
+            TipoChavePix::Email => "EMAIL",
+            TipoChavePix::Telefone => "PHONE",
+            TipoChavePix::Aleatoria => "EVP",
+        }
+    }
+
+    pub fn nome(&self) -> &'static str {
+        match self {
+            TipoChavePix::Cpf => "CPF",
+            TipoChavePix::Cnpj => "CNPJ",
+            TipoChavePix::Email => "E-mail",
+            TipoChavePix::Telefone => "Telefone celular",
+            TipoChavePix::Aleatoria => "Chave aleatória",
+        }
+    }
+
+    /// Tipo indicado pelo formato da chave; CPF e CNPJ são diferenciados pela quantidade de caracteres
+    fn detectar(chave: &str) -> Result<TipoChavePix, ErroValidacao> {
+        let alfanumericos = somente_alfanumericos(chave);
+        if chave.contains('@') {
+            Ok(TipoChavePix::Email)
+        } else if chave.starts_with('+') {
+            Ok(TipoChavePix::Telefone)
+        } else if chave.len() == 36 && chave.matches('-').count() == 4 {
+            Ok(TipoChavePix::Aleatoria)
+        } else if alfanumericos.len() == 11 && alfanumericos.bytes().all(|c| c.is_ascii_digit()) {
+            Ok(TipoChavePix::Cpf)
+        } else if alfanumericos.len() == 14 {
+            Ok(TipoChavePix::Cnpj)
+        } else {
+            Err(ErroValidacao::TipoInvalido {
+                encontrado: chave.to_string(),
+            })
+        }
+    }
+
+    /// Valida a chave e retorna a forma normalizada
+    fn normalizar(&self, chave: &str) -> Result<String, ErroValidacao> {
+        match self {
+            TipoChavePix::Cpf => CpfBuf::try_from(chave).map(|cpf| cpf.as_str().to_string()),
+            TipoChavePix::Cnpj => CnpjBuf::try_from(chave).map(|cnpj| cnpj.as_str().to_string()),
+            TipoChavePix::Email => normalizar_email(chave),
+            TipoChavePix::Telefone => normalizar_telefone(chave),
+            TipoChavePix::Aleatoria => normalizar_aleatoria(chave),
+        }
+    }
+}
+
+/// Aceita o identificador do DICT ou o nome em português, como `email`, `telefone` ou `aleatoria`
+impl FromStr for TipoChavePix {
+    type Err = ErroValidacao;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "cpf" => Ok(TipoChavePix::Cpf),
+            "cnpj" => Ok(TipoChavePix::Cnpj),
+            "email" | "e-mail" => Ok(TipoChavePix::Email),
+            "phone" | "telefone" | "celular" => Ok(TipoChavePix::Telefone),
+            "evp" | "aleatoria" => Ok(TipoChavePix::Aleatoria),
+            _ => Err(ErroValidacao::TipoInvalido {
+                encontrado: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for TipoChavePix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.identificador())
+    }
+}
+
+/// Letras, dígitos e hífens, sem hífen no início ou no fim
+fn rotulo_dominio(rotulo: &str) -> bool {
+    (1..=63).contains(&rotulo.len())
+        && rotulo.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'-')
+        && !rotulo.starts_with('-')
+        && !rotulo.ends_with('-')
+}
+
+/// `usuario@dominio.tld` em minúsculas, com no máximo 77 caracteres
+fn normalizar_email(chave: &str) -> Result<String, ErroValidacao> {
+    let email = chave.trim().to_lowercase();
+    let valido = match email.split_once('@') {
+        Some((usuario, dominio)) => {
+            let rotulos: Vec<&str> = dominio.split('.').collect();
+            email.len() <= TAMANHO_MAXIMO_EMAIL
+                && (1..=64).contains(&usuario.len())
+                && usuario.chars().all(|c| c.is_ascii_alphanumeric() || CARACTERES_EMAIL.contains(c))
+                && !usuario.starts_with('.')
+                && !usuario.ends_with('.')
+                && !usuario.contains("..")
+                && rotulos.len() >= 2
+                && rotulos.iter().all(|rotulo| rotulo_dominio(rotulo))
+                && rotulos.last().is_some_and(|tld| tld.len() >= 2 && tld.bytes().all(|c| c.is_ascii_alphabetic()))
+        }
+        None => false,
+    };
+    if valido {
+        Ok(email)
+    } else {
+        Err(campo_invalido("e-mail", chave))
+    }
+}
+
+/// Celular no formato E.164, `+55` seguido do DDD e do número de 9 dígitos iniciado em 9, `+5511987654321`
+/// Espaços, parênteses e hífens são removidos; sem o `+`, o código do país é acrescentado
+fn normalizar_telefone(chave: &str) -> Result<String, ErroValidacao> {
+    let sem_separadores: String = chave
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '(' | ')' | '-'))
+        .collect();
+    let digitos = match sem_separadores.strip_prefix('+') {
+        Some(digitos) => digitos.to_string(),
+        None => format!("55{sem_separadores}"),
+    };
+    let bytes = digitos.as_bytes();
+    let valido = bytes.len() == 13
+        && bytes.iter().all(u8::is_ascii_digit)
+        && digitos.starts_with("55")
+        // os DDDs vão de 11 a 99 e não possuem o dígito 0
+        && bytes[2] != b'0'
+        && bytes[3] != b'0'
+        && bytes[4] == b'9';
+    if valido {
+        Ok(format!("+{digitos}"))
+    } else {
+        Err(campo_invalido("telefone", chave))
+    }
+}
+
+/// UUID versão 4 em minúsculas, `123e4567-e89b-42d3-a456-426614174000`
+fn normalizar_aleatoria(chave: &str) -> Result<String, ErroValidacao> {
+    let uuid = chave.trim().to_ascii_lowercase();
+    let bytes = uuid.as_bytes();
+    let valido = bytes.len() == 36
+        && bytes.iter().enumerate().all(|(posicao, c)| match posicao {
+            8 | 13 | 18 | 23 => *c == b'-',
+            _ => c.is_ascii_hexdigit(),
+        })
+        && bytes[14] == b'4'
+        && matches!(bytes[19], b'8' | b'9' | b'a' | b'b');
+    if valido {
+        Ok(uuid)
+    } else {
+        Err(campo_invalido("chave aleatória", chave))
+    }
+}
+
+impl ChavePix {
+    pub fn tipo(&self) -> TipoChavePix {
+        self.tipo
+    }
+
+    /// A chave normalizada, como registrada no DICT
+    pub fn as_str(&self) -> &str {
+        &self.chave
+    }
+}
+
+/// Detecta o tipo pelo formato da chave; o telefone deve começar com `+`
+impl TryFrom<&str> for ChavePix {
+    type Error = ErroValidacao;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let tipo = TipoChavePix::detectar(value.trim())?;
+        ChavePix::try_from((tipo, value))
+    }
+}
+
+/// Valida a chave como o tipo informado; o telefone pode ser informado sem o `+55`
+impl TryFrom<(TipoChavePix, &str)> for ChavePix {
+    type Error = ErroValidacao;
+
+    fn try_from((tipo, chave): (TipoChavePix, &str)) -> Result<Self, Self::Error> {
+        Ok(ChavePix {
+            tipo,
+            chave: tipo.normalizar(chave)?,
+        })
+    }
+}
+
+impl TryFrom<String> for ChavePix {
+    type Error = ErroValidacao;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl FromStr for ChavePix {
+    type Err = ErroValidacao;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl AsRef<str> for ChavePix {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// A chave normalizada
+impl fmt::Display for ChavePix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// CPF e CNPJ com a máscara oficial e o telefone como `+55 (11) 98765-4321`; e-mail e chave aleatória não possuem máscara
+impl Formatar for ChavePix {
+    fn canonico(&self) -> String {
+        self.chave.clone()
+    }
+
+    fn formatar(&self) -> String {
+        match self.tipo {
+            TipoChavePix::Cpf => aplicar_mascara(&self.chave, "###.###.###-##"),
+            TipoChavePix::Cnpj => aplicar_mascara(&self.chave, "##.###.###/####-##"),
+            TipoChavePix::Telefone => aplicar_mascara(&self.chave, "### (##) #####-####"),
+            TipoChavePix::Email | TipoChavePix::Aleatoria => self.chave.clone(),
+        }
+    }
+}
+
+/// Detecta o tipo e valida a chave
+impl Validador for ChavePix {
+    fn verificar_com(numero: &str, _politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        ChavePix::try_from(numero).map(|_| ())
+    }
+
+    fn validar(&self) -> bool {
+        Self::is_valid(self.as_str())
+    }
+}
@@ -0,0 +1,123 @@
+//! Pseudonimização de documentos com chave, disponível com a feature `pseudonimizacao`
+//! O pseudônimo é o HMAC-SHA256 do tipo e dos dígitos normalizados, de modo que `255.248.930-33` e `25524893033` geram o mesmo valor
+//! Sem a chave não é possível recuperar o documento, nem testar os cerca de um bilhão de CPFs possíveis
+//!```rust
+//! # use validador_br::pseudonimizacao::Pseudonimizador;
+//! # use validador_br::types::TipoDocumento;
+//! let pseudonimizador = Pseudonimizador::new(b"chave secreta");
+//! let a = pseudonimizador.pseudonimo_de(TipoDocumento::Cpf, "255.248.930-33").unwrap();
+//! let b = pseudonimizador.pseudonimo_de(TipoDocumento::Cpf, "25524893033").unwrap();
+//! assert_eq!(a, b);
+//! assert_eq!(a.len(), 64);
+//!```
+
+use std::fmt;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::erro::ErroValidacao;
+use crate::gerador_dv::GeradorDv;
+use crate::types::{Cnh, Cnpj, Cns, Cpf, Documento, Pis, Renavam, Rg, TipoDocumento, TituloEleitor};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Gera pseudônimos determinísticos a partir de uma chave informada pelo chamador
+/// A mesma chave deve ser usada em todas as cargas para que os pseudônimos possam ser cruzados
+pub struct Pseudonimizador {
+    chave: Vec<u8>,
+}
+
+/// Não exibe a chave
+impl fmt::Debug for Pseudonimizador {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pseudonimizador").finish_non_exhaustive()
+    }
+}
+
+impl Pseudonimizador {
+    pub fn new(chave: impl AsRef<[u8]>) -> Self {
+        Pseudonimizador {
+            chave: chave.as_ref().to_vec(),
+        }
+    }
+
+    fn hmac(&self, mensagem: &[u8]) -> [u8; 32] {
+        let mut mac = HmacSha256::new_from_slice(&self.chave).expect("o HMAC aceita chaves de qualquer tamanho");
+        mac.update(mensagem);
+        mac.finalize().into_bytes().into()
+    }
+
+    /// HMAC-SHA256 de `tipo:digitos`, em hexadecimal
+    /// O tipo faz parte da mensagem, de modo que um CPF e um Pis com os mesmos dígitos geram pseudônimos diferentes
+    pub fn pseudonimo(&self, documento: &Documento) -> String {
+        self.hmac(normalizado(documento).as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// Valida e normaliza o número antes de gerar o pseudônimo
+    pub fn pseudonimo_de(&self, tipo: TipoDocumento, numero: &str) -> Result<String, ErroValidacao> {
+        Documento::try_from((tipo, numero)).map(|documento| self.pseudonimo(&documento))
+    }
+
+    /// Token que preserva o formato: um documento válido do mesmo tipo, derivado do HMAC
+    /// Útil para ambientes de homologação e sistemas que validam o dígito verificador
+    /// ⚠ Com apenas 9 dígitos de base no CPF, documentos diferentes podem gerar o mesmo token; use `pseudonimo` como chave de junção
+    ///
+    /// Disponível para CPF, CNPJ, Pis, Cns, Título de Eleitor, CNH, Renavam e Rg; os demais tipos retornam `ErroValidacao::NaoImplementado`
+    pub fn token(&self, documento: &Documento) -> Result<Documento, ErroValidacao> {
+        let tipo = documento.tipo();
+        let mensagem = normalizado(documento);
+        for contador in 0u32.. {
+            let digitos = digitos(&self.hmac(format!("{mensagem}:{contador}").as_bytes()));
+            if digitos.len() < 12 {
+                continue;
+            }
+            let texto = |inicio: usize, quantidade: usize| -> String {
+                digitos[inicio..inicio + quantidade].iter().map(|d| d.to_string()).collect()
+            };
+            let completo = match tipo {
+                TipoDocumento::Cpf => Cpf::completar(&texto(0, 9)),
+                TipoDocumento::Cnpj => Cnpj::completar(&texto(0, 12)),
+                TipoDocumento::Pis => Pis::completar(&texto(0, 10)),
+                TipoDocumento::Cnh => Cnh::completar(&texto(0, 9)),
+                TipoDocumento::Renavam => Renavam::completar(&texto(0, 10)),
+                TipoDocumento::Rg => Rg::completar(&texto(0, 8)),
+                // cartão definitivo, iniciado em 1 ou 2
+                TipoDocumento::Cns => Cns::completar(&format!("{}{}", 1 + digitos[0] % 2, texto(1, 10))),
+                // o código da UF vai de 01 a 28
+                TipoDocumento::TituloEleitor => {
+                    let uf = (digitos[8] * 10 + digitos[9]) % 28 + 1;
+                    TituloEleitor::completar(&format!("{}{uf:02}", texto(0, 8)))
+                }
+                TipoDocumento::Ie | TipoDocumento::CodigoBarrasGs1 | TipoDocumento::CartaoCredito => {
+                    return Err(ErroValidacao::NaoImplementado)
+                }
+            };
+            // bases sem dígito possível ou com dígitos repetidos são descartadas e o contador avança
+            if let Ok(token) = completo.and_then(|completo| Documento::try_from((tipo, completo.as_str()))) {
+                return Ok(token);
+            }
+        }
+        unreachable!("o contador não se esgota antes de encontrar um token válido")
+    }
+}
+
+/// `cpf:25524893033`; a Inscrição Estadual inclui a UF, `ie:SP110042490114`
+fn normalizado(documento: &Documento) -> String {
+    match documento {
+        Documento::Ie(ie) => format!("{}:{}{}", documento.tipo(), ie.uf(), ie.as_str()),
+        _ => format!("{}:{}", documento.tipo(), documento.as_str()),
+    }
+}
+
+/// Converte os bytes em dígitos sem viés, descartando os valores de 250 a 255
+fn digitos(bytes: &[u8]) -> Vec<u32> {
+    bytes
+        .iter()
+        .filter(|&&byte| byte < 250)
+        .map(|&byte| (byte % 10) as u32)
+        .collect()
+}
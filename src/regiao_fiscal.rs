@@ -0,0 +1,98 @@
+//! Regiões fiscais da Receita Federal, identificadas pelo 9º dígito do CPF
+//!```rust
+//! # use validador_br::types::{Cpf, RegiaoFiscal, Uf};
+//! let regiao = Cpf("255.248.930-33").regiao_fiscal().unwrap();
+//! assert_eq!(regiao, RegiaoFiscal::Decima);
+//! assert_eq!(regiao.ufs(), &[Uf::RS]);
+//! assert_eq!(RegiaoFiscal::de_uf(Uf::SP).digito(), 8);
+//!```
+
+use std::fmt;
+
+use crate::erro::ErroValidacao;
+use crate::owned::CpfBuf;
+use crate::types::{Cpf, RegiaoFiscal, Uf};
+use crate::validador::PoliticaRepeticao;
+
+impl RegiaoFiscal {
+    /// Todas as regiões, da 1ª à 10ª
+    pub const TODAS: [RegiaoFiscal; 10] = [
+        RegiaoFiscal::Primeira,
+        RegiaoFiscal::Segunda,
+        RegiaoFiscal::Terceira,
+        RegiaoFiscal::Quarta,
+        RegiaoFiscal::Quinta,
+        RegiaoFiscal::Sexta,
+        RegiaoFiscal::Setima,
+        RegiaoFiscal::Oitava,
+        RegiaoFiscal::Nona,
+        RegiaoFiscal::Decima,
+    ];
+
+    /// Número ordinal da região, de 1 a 10
+    pub fn numero(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    /// 9º dígito do CPF, de 0 a 9; a 10ª região usa o dígito 0
+    pub fn digito(&self) -> u8 {
+        self.numero() % 10
+    }
+
+    pub fn from_digito(digito: u8) -> Option<RegiaoFiscal> {
+        match digito {
+            0 => Some(RegiaoFiscal::Decima),
+            1..=9 => Some(RegiaoFiscal::TODAS[digito as usize - 1]),
+            _ => None,
+        }
+    }
+
+    /// Unidades da Federação atendidas pela região
+    pub fn ufs(&self) -> &'static [Uf] {
+        match self {
+            RegiaoFiscal::Primeira => &[Uf::DF, Uf::GO, Uf::MS, Uf::MT, Uf::TO],
+            RegiaoFiscal::Segunda => &[Uf::AC, Uf::AM, Uf::AP, Uf::PA, Uf::RO, Uf::RR],
+            RegiaoFiscal::Terceira => &[Uf::CE, Uf::MA, Uf::PI],
+            RegiaoFiscal::Quarta => &[Uf::AL, Uf::PB, Uf::PE, Uf::RN],
+            RegiaoFiscal::Quinta => &[Uf::BA, Uf::SE],
+            RegiaoFiscal::Sexta => &[Uf::MG],
+            RegiaoFiscal::Setima => &[Uf::ES, Uf::RJ],
+            RegiaoFiscal::Oitava => &[Uf::SP],
+            RegiaoFiscal::Nona => &[Uf::PR, Uf::SC],
+            RegiaoFiscal::Decima => &[Uf::RS],
+        }
+    }
+
+    /// Região fiscal que atende a UF
+    pub fn de_uf(uf: Uf) -> RegiaoFiscal {
+        RegiaoFiscal::TODAS
+            .into_iter()
+            .find(|regiao| regiao.ufs().contains(&uf))
+            .expect("todas as UFs pertencem a uma região fiscal")
+    }
+}
+
+/// `8ª Região Fiscal`
+impl fmt::Display for RegiaoFiscal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}ª Região Fiscal", self.numero())
+    }
+}
+
+impl Cpf<'_> {
+    /// Região fiscal de emissão, indicada pelo 9º dígito; o CPF é validado antes
+    pub fn regiao_fiscal(&self) -> Result<RegiaoFiscal, ErroValidacao> {
+        let digitos = Self::digitos_validos(self.0, PoliticaRepeticao::default())?;
+        Ok(regiao_do_digito(digitos[8]))
+    }
+}
+
+impl CpfBuf {
+    pub fn regiao_fiscal(&self) -> RegiaoFiscal {
+        regiao_do_digito(self.digitos()[8] as u32)
+    }
+}
+
+fn regiao_do_digito(digito: u32) -> RegiaoFiscal {
+    RegiaoFiscal::from_digito(digito as u8).expect("o 9º dígito está entre 0 e 9")
+}
@@ -0,0 +1,148 @@
+//! Suporte ao `serde`, disponível com a feature `serde`
+//! A desserialização valida o número, de modo que dígitos verificadores incorretos falham já na leitura do payload
+//! Por padrão os documentos são serializados somente com os dígitos; use os módulos `formatado` ou `canonico` com `#[serde(with = "...")]` para escolher
+//!```rust
+//! # use serde::{Deserialize, Serialize};
+//! # use validador_br::owned::{CnpjBuf, CpfBuf};
+//! #[derive(Serialize, Deserialize)]
+//! struct Cliente {
+//!     cpf: CpfBuf,
+//!     #[serde(with = "validador_br::serializacao::formatado")]
+//!     cnpj: CnpjBuf,
+//! }
+//!
+//! let cliente: Cliente = serde_json::from_str(r#"{"cpf": "255.248.930-33", "cnpj": "76553412000110"}"#).unwrap();
+//! assert_eq!(
+//!     serde_json::to_string(&cliente).unwrap(),
+//!     r#"{"cpf":"25524893033","cnpj":"76.553.412/0001-10"}"#
+//! );
+//! assert!(serde_json::from_str::<Cliente>(r#"{"cpf": "255.248.930-34", "cnpj": "76553412000110"}"#).is_err());
+//!```
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::formatar::Formatar;
+use crate::owned::{CartaoCreditoBuf, CnhBuf, CnpjBuf, CnsBuf, CodigoBarrasGs1Buf, CpfBuf, IeBuf,
+    PisBuf, RenavamBuf, RgBuf, TituloEleitorBuf,
+};
+use crate::types::{CartaoCredito, ChavePix, Cnh, Cnpj, Cns, CodigoBarrasGs1, Cpf, Ie, Pis, Renavam,
+    Rg, TituloEleitor,
+};
+use crate::validador_ie::IeUf;
+
+/// Representação textual usada na serialização
+/// A Inscrição Estadual inclui a sigla da UF, para que possa ser desserializada novamente
+pub trait Serializar {
+    fn serializar(&self, formatado: bool) -> String;
+}
+
+macro_rules! serializar {
+    ($($tipo:ty),*) => {
+        $(
+            impl Serializar for $tipo {
+                fn serializar(&self, formatado: bool) -> String {
+                    if formatado {
+                        self.formatar()
+                    } else {
+                        self.canonico()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+serializar!(Cpf<'_>, Cnpj<'_>, TituloEleitor<'_>, Cnh<'_>, Renavam<'_>, Rg<'_>, Cns<'_>, Pis<'_>,
+    CartaoCredito<'_>, CodigoBarrasGs1<'_>, CpfBuf, CnpjBuf, TituloEleitorBuf, CnhBuf, RenavamBuf,
+    RgBuf, CnsBuf, PisBuf, CartaoCreditoBuf, CodigoBarrasGs1Buf, ChavePix);
+
+/// `SP 110042490114` ou `SP 110.042.490.114`
+impl Serializar for IeUf<'_> {
+    fn serializar(&self, formatado: bool) -> String {
+        let numero = if formatado { self.formatar() } else { self.canonico() };
+        format!("{} {}", self.0, numero)
+    }
+}
+
+impl Serializar for Ie<'_> {
+    fn serializar(&self, formatado: bool) -> String {
+        self.0.serializar(formatado)
+    }
+}
+
+impl Serializar for IeBuf {
+    fn serializar(&self, formatado: bool) -> String {
+        self.as_documento().serializar(formatado)
+    }
+}
+
+/// Serializa com a máscara oficial, `255.248.930-33`
+pub mod formatado {
+    use super::*;
+
+    pub fn serialize<T: Serializar, S: Serializer>(documento: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&documento.serializar(true))
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+}
+
+/// Serializa somente com os dígitos, `25524893033`
+pub mod canonico {
+    use super::*;
+
+    pub fn serialize<T: Serializar, S: Serializer>(documento: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&documento.serializar(false))
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+}
+
+macro_rules! serde_proprio {
+    ($($nome:ident),*) => {
+        $(
+            impl Serialize for $nome {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    canonico::serialize(self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $nome {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let numero = String::deserialize(deserializer)?;
+                    $nome::try_from(numero.as_str()).map_err(D::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+/// Os documentos emprestados só podem ser desserializados de textos sem caracteres de escape
+macro_rules! serde_emprestado {
+    ($($tipo:ident),*) => {
+        $(
+            impl Serialize for $tipo<'_> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    canonico::serialize(self, serializer)
+                }
+            }
+
+            impl<'de: 'data, 'data> Deserialize<'de> for $tipo<'data> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let numero = <&'de str>::deserialize(deserializer)?;
+                    $tipo::try_from(numero).map_err(D::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+serde_proprio!(CpfBuf, CnpjBuf, TituloEleitorBuf, CnhBuf, RenavamBuf, RgBuf, CnsBuf, PisBuf,
+    CartaoCreditoBuf, CodigoBarrasGs1Buf, IeBuf, ChavePix);
+serde_emprestado!(Cpf, Cnpj, TituloEleitor, Cnh, Renavam, Rg, Cns, Pis, CartaoCredito,
+    CodigoBarrasGs1, IeUf, Ie);
@@ -0,0 +1,81 @@
+//! Códigos de UF do Título de Eleitor, de 01 (SP) a 28 (exterior)
+//! Os códigos seguem a ordem de numeração do TSE, e não o código do IBGE
+//!```rust
+//! # use validador_br::types::{TituloEleitor, Uf, UfTitulo};
+//! assert_eq!(TituloEleitor("0043 5687 0906").uf(), Ok(UfTitulo::Uf(Uf::SC)));
+//! assert_eq!(UfTitulo::from_codigo(28), Some(UfTitulo::Exterior));
+//! assert_eq!(UfTitulo::Exterior.to_string(), "ZZ");
+//!```
+
+use std::fmt;
+
+use crate::erro::ErroValidacao;
+use crate::owned::TituloEleitorBuf;
+use crate::types::{TituloEleitor, Uf, UfTitulo};
+use crate::validador::{PoliticaRepeticao, Validador};
+
+/// UFs na ordem dos códigos 01 a 27
+const UFS: [Uf; 27] = [
+    Uf::SP, Uf::MG, Uf::RJ, Uf::RS, Uf::BA, Uf::PR, Uf::CE, Uf::PE, Uf::SC,
+    Uf::GO, Uf::MA, Uf::PB, Uf::PA, Uf::ES, Uf::PI, Uf::RN, Uf::AL, Uf::MT,
+    Uf::MS, Uf::DF, Uf::SE, Uf::AM, Uf::RO, Uf::AC, Uf::AP, Uf::RR, Uf::TO,
+];
+
+impl UfTitulo {
+    pub fn from_codigo(codigo: u8) -> Option<UfTitulo> {
+        match codigo {
+            1..=27 => Some(UfTitulo::Uf(UFS[codigo as usize - 1])),
+            28 => Some(UfTitulo::Exterior),
+            _ => None,
+        }
+    }
+
+    pub fn codigo(&self) -> u8 {
+        match self {
+            UfTitulo::Uf(uf) => UFS.iter().position(|item| item == uf).expect("todas as UFs possuem código") as u8 + 1,
+            UfTitulo::Exterior => 28,
+        }
+    }
+
+    pub fn sigla(&self) -> &'static str {
+        match self {
+            UfTitulo::Uf(uf) => uf.sigla(),
+            UfTitulo::Exterior => "ZZ",
+        }
+    }
+
+    /// Em SP e MG, quando o resto da divisão por 11 é 0, o dígito verificador é 1
+    pub(crate) fn resto_zero_vale_um(&self) -> bool {
+        matches!(self, UfTitulo::Uf(Uf::SP | Uf::MG))
+    }
+}
+
+impl fmt::Display for UfTitulo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.sigla())
+    }
+}
+
+/// Obtém a UF a partir do 9º e 10º dígitos, retornando `ErroValidacao::UfInvalida` fora da faixa 01 a 28
+pub(crate) fn uf_titulo(digitos: &[u32]) -> Result<UfTitulo, ErroValidacao> {
+    let codigo = digitos[8] * 10 + digitos[9];
+    UfTitulo::from_codigo(codigo as u8).ok_or(ErroValidacao::UfInvalida {
+        encontrado: format!("{codigo:02}"),
+    })
+}
+
+impl TituloEleitor<'_> {
+    /// UF de emissão; o título é validado antes
+    pub fn uf(&self) -> Result<UfTitulo, ErroValidacao> {
+        Self::verificar_com(self.0, PoliticaRepeticao::default())?;
+        let digitos: Vec<u32> = self.0.chars().filter_map(|c| c.to_digit(10)).collect();
+        uf_titulo(&digitos)
+    }
+}
+
+impl TituloEleitorBuf {
+    pub fn uf(&self) -> UfTitulo {
+        let digitos = self.digitos().map(u32::from);
+        uf_titulo(&digitos).expect("o título foi validado na construção")
+    }
+}
@@ -1,10 +1,12 @@
+use crate::owned::{CartaoCreditoBuf, CnhBuf, CnpjBuf, CnsBuf, CodigoBarrasGs1Buf, CpfBuf, IeBuf,
+    PisBuf, RenavamBuf, RgBuf, TituloEleitorBuf,
+};
 use crate::validador_ie::IeUf;
 /// Cadastro de Pessoa Física
 pub struct Cpf<'data>(pub &'data str);
 /// Cadastro de Pessoa Jurídica
 pub struct Cnpj<'data>(pub &'data str);
 /// Inscrição Estadual
-#[allow(dead_code)]
 pub struct Ie<'data>(pub IeUf<'data>);
 /// Cartão de Crédito
 pub struct CartaoCredito<'data>(pub &'data str);
@@ -25,4 +27,177 @@ pub enum TipoCns<'data> {
 /// Código de Barras Gs1 7890300584651
 pub struct CodigoBarrasGs1<'data>(pub &'data str);
 /// Programa de Integração Social, PIS, PASEP, NIT
-pub struct Pis<'data>(pub &'data str);
\ No newline at end of file
+pub struct Pis<'data>(pub &'data str);
+/// Boleto bancário, pela linha digitável de 47 dígitos ou pelo código de barras de 44 dígitos
+pub struct Boleto<'data>(pub &'data str);
+/// Boleto de arrecadação de concessionárias e tributos, iniciado em 8, pela linha digitável de 48 dígitos ou pelo código de barras de 44 dígitos
+pub struct Arrecadacao<'data>(pub &'data str);
+/// Segmento do boleto de arrecadação, indicado pelo 2º dígito
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Segmento {
+    Prefeituras,
+    Saneamento,
+    EnergiaGas,
+    Telecomunicacoes,
+    OrgaosGovernamentais,
+    /// Carnês e demais empresas identificadas pelo CNPJ
+    Carnes,
+    MultasTransito,
+    UsoBanco,
+}
+/// Chave de acesso de 44 dígitos dos documentos fiscais eletrônicos: NF-e, NFC-e, CT-e, CT-e OS e MDF-e
+pub struct ChaveAcesso<'data>(pub &'data str);
+/// Modelo do documento fiscal, indicado pelo 21º e 22º dígitos da chave de acesso
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ModeloFiscal {
+    Nfe,
+    Nfce,
+    Cte,
+    Mdfe,
+    CteOs,
+}
+/// Emitente do documento fiscal; o CPF ocupa os 11 últimos dígitos do campo, precedido de zeros
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Emitente {
+    Cnpj(CnpjBuf),
+    Cpf(CpfBuf),
+}
+/// Campos da chave de acesso, sem o dígito verificador
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PartesChaveAcesso {
+    pub uf: Uf,
+    /// Ano de emissão, de 2000 a 2099
+    pub ano: u16,
+    pub mes: u8,
+    pub emitente: Emitente,
+    pub modelo: ModeloFiscal,
+    pub serie: u16,
+    pub numero: u32,
+    /// Forma de emissão, `1` para a emissão normal
+    pub tipo_emissao: u8,
+    /// Código numérico de 8 dígitos gerado pelo emitente
+    pub codigo_numerico: u32,
+}
+/// Chave PIX validada e normalizada no formato do DICT
+/// Construída por meio de `TryFrom`, que detecta o tipo, ou de `TryFrom<(TipoChavePix, &str)>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ChavePix {
+    pub(crate) tipo: TipoChavePix,
+    pub(crate) chave: String,
+}
+/// Tipos de chave PIX aceitos pelo DICT
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TipoChavePix {
+    Cpf,
+    Cnpj,
+    Email,
+    Telefone,
+    /// Chave aleatória (EVP), um UUID versão 4
+    Aleatoria,
+}
+/// Código PIX copia e cola (BR Code), no padrão EMV de QR Code
+/// O PIX estático traz a chave; o dinâmico traz a URL da cobrança, consultada pelo pagador
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BrCode {
+    pub chave: Option<ChavePix>,
+    /// URL da cobrança do PIX dinâmico, sem o `https://`
+    pub url: Option<String>,
+    /// Mensagem exibida ao pagador
+    pub info_adicional: Option<String>,
+    /// Valor em centavos; sem valor, o pagador informa o valor no pagamento
+    pub valor: Option<u64>,
+    /// Nome do recebedor, até 25 caracteres
+    pub nome: String,
+    /// Cidade do recebedor, até 15 caracteres
+    pub cidade: String,
+    /// Identificador da transação, até 25 letras e dígitos
+    pub txid: Option<String>,
+    /// Indica um código que só pode ser pago uma vez
+    pub unico: bool,
+}
+/// Data do calendário gregoriano, usada no vencimento do boleto
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Data {
+    pub ano: u16,
+    pub mes: u8,
+    pub dia: u8,
+}
+/// UF de emissão do Título de Eleitor, indicada pelo 9º e 10º dígitos
+/// Eleitores inscritos no exterior usam o código 28, exibido como `ZZ`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UfTitulo {
+    Uf(Uf),
+    Exterior,
+}
+/// Região fiscal da Receita Federal, indicada pelo 9º dígito do CPF
+/// O dígito 0 corresponde à 10ª região
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RegiaoFiscal {
+    Primeira,
+    Segunda,
+    Terceira,
+    Quarta,
+    Quinta,
+    Sexta,
+    Setima,
+    Oitava,
+    Nona,
+    Decima,
+}
+/// Bandeira do cartão de crédito, identificada pelos primeiros dígitos (IIN/BIN)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Bandeira {
+    Visa,
+    Mastercard,
+    Amex,
+    Elo,
+    Hipercard,
+    Diners,
+    Discover,
+    Jcb,
+}
+/// Unidades da Federação: 26 estados e o Distrito Federal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Uf {
+    AC, AL, AP, AM, BA, CE, DF, ES, GO, MA, MT, MS, MG, PA,
+    PB, PR, PE, PI, RJ, RN, RS, RO, RR, SC, SP, SE, TO,
+}
+/// Grandes regiões do IBGE
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Regiao {
+    Norte,
+    Nordeste,
+    CentroOeste,
+    Sudeste,
+    Sul,
+}
+/// Qualquer um dos documentos suportados, já validado e sem lifetime
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Documento {
+    Cpf(CpfBuf),
+    Cnpj(CnpjBuf),
+    Cns(CnsBuf),
+    Pis(PisBuf),
+    TituloEleitor(TituloEleitorBuf),
+    Cnh(CnhBuf),
+    Renavam(RenavamBuf),
+    Rg(RgBuf),
+    Ie(IeBuf),
+    CodigoBarrasGs1(CodigoBarrasGs1Buf),
+    CartaoCredito(CartaoCreditoBuf),
+}
+/// Identifica o tipo de um `Documento`, sem o número
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TipoDocumento {
+    Cpf,
+    Cnpj,
+    Cns,
+    Pis,
+    TituloEleitor,
+    Cnh,
+    Renavam,
+    Rg,
+    Ie,
+    CodigoBarrasGs1,
+    CartaoCredito,
+}
\ No newline at end of file
@@ -0,0 +1,213 @@
+//! Métodos auxiliares do tipo `Uf`: sigla, nome, código do IBGE e região
+//!```rust
+//! # use validador_br::types::{Regiao, Uf};
+//! let uf: Uf = "São Paulo".parse().unwrap();
+//! assert_eq!(uf, Uf::SP);
+//! assert_eq!(uf.codigo_ibge(), 35);
+//! assert_eq!(uf.regiao(), Regiao::Sudeste);
+//! assert_eq!(uf.to_string(), "SP");
+//!```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::erro::ErroValidacao;
+use crate::types::{Regiao, Uf};
+
+impl Uf {
+    /// Todas as unidades da federação, em ordem alfabética do nome do estado
+    pub const TODAS: [Uf; 27] = [
+        Uf::AC, Uf::AL, Uf::AP, Uf::AM, Uf::BA, Uf::CE, Uf::DF, Uf::ES, Uf::GO,
+        Uf::MA, Uf::MT, Uf::MS, Uf::MG, Uf::PA, Uf::PB, Uf::PR, Uf::PE, Uf::PI,
+        Uf::RJ, Uf::RN, Uf::RS, Uf::RO, Uf::RR, Uf::SC, Uf::SP, Uf::SE, Uf::TO,
+    ];
+
+    /// Sigla de duas letras, `Uf::SP.sigla() == "SP"`
+    pub fn sigla(&self) -> &'static str {
+        match self {
+            Uf::AC => "AC",
+            Uf::AL => "AL",
+            Uf::AP => "AP",
+            Uf::AM => "AM",
+            Uf::BA => "BA",
+            Uf::CE => "CE",
+            Uf::DF => "DF",
+            Uf::ES => "ES",
+            Uf::GO => "GO",
+            Uf::MA => "MA",
+            Uf::MT => "MT",
+            Uf::MS => "MS",
+            Uf::MG => "MG",
+            Uf::PA => "PA",
+            Uf::PB => "PB",
+            Uf::PR => "PR",
+            Uf::PE => "PE",
+            Uf::PI => "PI",
+            Uf::RJ => "RJ",
+            Uf::RN => "RN",
+            Uf::RS => "RS",
+            Uf::RO => "RO",
+            Uf::RR => "RR",
+            Uf::SC => "SC",
+            Uf::SP => "SP",
+            Uf::SE => "SE",
+            Uf::TO => "TO",
+        }
+    }
+
+    /// Localiza a UF pela sigla, sem diferenciar maiúsculas e minúsculas
+    pub fn from_sigla(sigla: &str) -> Option<Uf> {
+        Uf::TODAS
+            .into_iter()
+            .find(|uf| uf.sigla().eq_ignore_ascii_case(sigla))
+    }
+
+    /// Localiza a UF pelo código numérico do IBGE, `Uf::from_codigo_ibge(35) == Some(Uf::SP)`
+    pub fn from_codigo_ibge(codigo: u8) -> Option<Uf> {
+        Uf::TODAS.into_iter().find(|uf| uf.codigo_ibge() == codigo)
+    }
+
+    /// Código numérico do IBGE, usado também na chave de acesso da NF-e
+    pub fn codigo_ibge(&self) -> u8 {
+        match self {
+            Uf::RO => 11,
+            Uf::AC => 12,
+            Uf::AM => 13,
+            Uf::RR => 14,
+            Uf::PA => 15,
+            Uf::AP => 16,
+            Uf::TO => 17,
+            Uf::MA => 21,
+            Uf::PI => 22,
+            Uf::CE => 23,
+            Uf::RN => 24,
+            Uf::PB => 25,
+            Uf::PE => 26,
+            Uf::AL => 27,
+            Uf::SE => 28,
+            Uf::BA => 29,
+            Uf::MG => 31,
+            Uf::ES => 32,
+            Uf::RJ => 33,
+            Uf::SP => 35,
+            Uf::PR => 41,
+            Uf::SC => 42,
+            Uf::RS => 43,
+            Uf::MS => 50,
+            Uf::MT => 51,
+            Uf::GO => 52,
+            Uf::DF => 53,
+        }
+    }
+
+    /// Nome por extenso, com acentuação
+    pub fn nome(&self) -> &'static str {
+        match self {
+            Uf::AC => "Acre",
+            Uf::AL => "Alagoas",
+            Uf::AP => "Amapá",
+            Uf::AM => "Amazonas",
+            Uf::BA => "Bahia",
+            Uf::CE => "Ceará",
+            Uf::DF => "Distrito Federal",
+            Uf::ES => "Espírito Santo",
+            Uf::GO => "Goiás",
+            Uf::MA => "Maranhão",
+            Uf::MT => "Mato Grosso",
+            Uf::MS => "Mato Grosso do Sul",
+            Uf::MG => "Minas Gerais",
+            Uf::PA => "Pará",
+            Uf::PB => "Paraíba",
+            Uf::PR => "Paraná",
+            Uf::PE => "Pernambuco",
+            Uf::PI => "Piauí",
+            Uf::RJ => "Rio de Janeiro",
+            Uf::RN => "Rio Grande do Norte",
+            Uf::RS => "Rio Grande do Sul",
+            Uf::RO => "Rondônia",
+            Uf::RR => "Roraima",
+            Uf::SC => "Santa Catarina",
+            Uf::SP => "São Paulo",
+            Uf::SE => "Sergipe",
+            Uf::TO => "Tocantins",
+        }
+    }
+
+    /// O primeiro dígito do código IBGE identifica a região
+    pub fn regiao(&self) -> Regiao {
+        match self.codigo_ibge() / 10 {
+            1 => Regiao::Norte,
+            2 => Regiao::Nordeste,
+            3 => Regiao::Sudeste,
+            4 => Regiao::Sul,
+            _ => Regiao::CentroOeste,
+        }
+    }
+}
+
+impl Regiao {
+    pub fn nome(&self) -> &'static str {
+        match self {
+            Regiao::Norte => "Norte",
+            Regiao::Nordeste => "Nordeste",
+            Regiao::CentroOeste => "Centro-Oeste",
+            Regiao::Sudeste => "Sudeste",
+            Regiao::Sul => "Sul",
+        }
+    }
+
+    /// Unidades da federação que compõem a região
+    pub fn ufs(&self) -> Vec<Uf> {
+        Uf::TODAS
+            .into_iter()
+            .filter(|uf| uf.regiao() == *self)
+            .collect()
+    }
+}
+
+/// Remove acentos e converte para minúsculas, para comparar nomes como `São Paulo` e `sao paulo`
+fn normalizar_nome(nome: &str) -> String {
+    nome.trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' => 'a',
+            'é' | 'ê' => 'e',
+            'í' => 'i',
+            'ó' | 'ô' | 'õ' => 'o',
+            'ú' | 'ü' => 'u',
+            'ç' => 'c',
+            c => c,
+        })
+        .collect()
+}
+
+/// Aceita a sigla (`SP`, `sp`) ou o nome do estado (`São Paulo`, `sao paulo`)
+impl FromStr for Uf {
+    type Err = ErroValidacao;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nome = normalizar_nome(s);
+        Uf::from_sigla(s.trim())
+            .or_else(|| {
+                Uf::TODAS
+                    .into_iter()
+                    .find(|uf| normalizar_nome(uf.nome()) == nome)
+            })
+            .ok_or(ErroValidacao::UfInvalida {
+                encontrado: s.to_string(),
+            })
+    }
+}
+
+impl fmt::Display for Uf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.sigla())
+    }
+}
+
+impl fmt::Display for Regiao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.nome())
+    }
+}
@@ -1,34 +1,81 @@
-use crate::funcoes::{calc_digito, calc_digito_mod11, completa_esquerda, mod_11, 
-onze_menos_mod11, somente_digitos,
+use crate::erro::ErroValidacao;
+use crate::funcoes::{verificar_caracteres, verificar_caracteres_alfanumericos, verificar_quantidade,
+    verificar_quantidade_alfanumericos,
 };
+use crate::gerador_dv::GeradorDv;
 
-use crate::types::{CartaoCredito, Cnh, Cnpj, Cns, CodigoBarrasGs1, Cpf, Pis, Renavam, 
-Rg, TipoCns, TituloEleitor,
+use crate::types::{Arrecadacao, Bandeira, Boleto, CartaoCredito, ChaveAcesso, Cnh, Cnpj, Cns,
+CodigoBarrasGs1, Cpf, Ie, Pis, Renavam, Rg, TipoCns, TituloEleitor,
 };
+use crate::validador_ie::{separar_uf, verificar_ie, IeUf};
+
+/// Define se sequências de dígitos repetidos, como `111.111.111-11`, são aceitas na validação
+/// O padrão é rejeitar, mas alguns sistemas legados armazenam esses números como valores provisórios
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PoliticaRepeticao {
+    #[default]
+    Rejeitar,
+    Permitir,
+}
+
+impl PoliticaRepeticao {
+    /// Retorna `ErroValidacao::DigitosRepetidos` se todos os dígitos forem iguais e a política for `Rejeitar`
+    pub fn verificar(&self, digitos: &[u32]) -> Result<(), ErroValidacao> {
+        let repetidos = digitos.windows(2).all(|par| par[0] == par[1]);
+        match self {
+            PoliticaRepeticao::Rejeitar if repetidos => Err(ErroValidacao::DigitosRepetidos),
+            _ => Ok(()),
+        }
+    }
+}
 
 pub trait Validador {
-    fn is_valid(_numero: &str) -> bool {
-        false
+    /// Valida o número e retorna a causa exata em caso de falha
+    fn verificar(numero: &str) -> Result<(), ErroValidacao> {
+        Self::verificar_com(numero, PoliticaRepeticao::default())
+    }
+
+    /// Valida o número aplicando a política de repetição informada
+    /// Documentos como Rg e Cartão de Crédito não analisam repetições e ignoram a política
+    fn verificar_com(_numero: &str, _politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        Err(ErroValidacao::NaoImplementado)
+    }
+
+    fn is_valid(numero: &str) -> bool {
+        Self::verificar(numero).is_ok()
     }
 
     fn validar(&self) -> bool;
 }
 
-impl Validador for Cpf<'_> {
-    fn is_valid(numero: &str) -> bool {
-        let multiplicadores = vec![10, 9, 8, 7, 6, 5, 4, 3, 2];
-        let digitos = somente_digitos(numero, 11);
-        let part1 = digitos[0..9].to_vec();
-        let dv1 = &digitos[9];
-        let part2 = digitos[1..10].to_vec();
-        let dv2 = &digitos[10];
-        let decima = |x: u32| mod_11(10 * x) as u32;
+/// Compara os dígitos verificadores calculados com os informados
+pub(crate) fn verificar_dv(esperado: Vec<u32>, encontrado: Vec<u32>) -> Result<(), ErroValidacao> {
+    if esperado == encontrado {
+        Ok(())
+    } else {
+        Err(ErroValidacao::DigitoVerificador {
+            esperado,
+            encontrado,
+        })
+    }
+}
+
+impl Cpf<'_> {
+    /// Valida o número e retorna os 11 dígitos, usados também para obter a região fiscal
+    pub(crate) fn digitos_validos(numero: &str, politica: PoliticaRepeticao) -> Result<Vec<u32>, ErroValidacao> {
+        verificar_caracteres(numero)?;
+        let digitos = verificar_quantidade(numero, 11, 11)?;
+        politica.verificar(&digitos)?;
+        let (base, dv) = digitos.split_at(9);
+
+        verificar_dv(Self::calcular_dv(base)?, dv.to_vec())?;
+        Ok(digitos)
+    }
+}
 
-        let digitos_verificadores: (u32, u32) = (
-            calc_digito(part1, multiplicadores.clone(), decima),
-            calc_digito(part2, multiplicadores, decima),
-        );
-        digitos_verificadores == (*dv1, *dv2)
+impl Validador for Cpf<'_> {
+    fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        Self::digitos_validos(numero, politica).map(|_| ())
     }
 
     fn validar(&self) -> bool {
@@ -36,22 +83,25 @@ impl Validador for Cpf<'_> {
     }
 }
 
+/// Aceita o CNPJ numérico e o alfanumérico, com letras de `A` a `Z` nas 12 primeiras posições
 impl Validador for Cnpj<'_> {
-    fn is_valid(numero: &str) -> bool {
-        let multiplicadores1 = vec![5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
-        let digitos = somente_digitos(numero, 14);
-        let part1 = digitos[0..12].to_vec();
-        let dv1 = &digitos[12];
-        let multiplicadores2 = vec![6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
-        let part2 = digitos[0..13].to_vec();
-        let dv2 = &digitos[13];
-        let decima = |x: u32| mod_11(10 * x) as u32;
-
-        let digitos_verificadores: (u32, u32) = (
-            calc_digito(part1, multiplicadores1, decima),
-            calc_digito(part2, multiplicadores2, decima),
-        );
-        digitos_verificadores == (*dv1, *dv2)
+    fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        verificar_caracteres_alfanumericos(numero)?;
+        let mut digitos = verificar_quantidade_alfanumericos(numero, 14, 14)?;
+        politica.verificar(&digitos)?;
+        // as letras são aceitas somente na raiz e na ordem; os dígitos verificadores são sempre numéricos
+        if let Some((posicao, caractere)) = numero
+            .chars()
+            .enumerate()
+            .filter(|(_, c)| c.is_ascii_alphanumeric())
+            .skip(12)
+            .find(|(_, c)| !c.is_ascii_digit())
+        {
+            return Err(ErroValidacao::CaractereInvalido { caractere, posicao });
+        }
+        let dv = digitos.split_off(12);
+
+        verificar_dv(Self::calcular_dv(&digitos)?, dv)
     }
 
     fn validar(&self) -> bool {
@@ -59,23 +109,22 @@ impl Validador for Cnpj<'_> {
     }
 }
 
+/// Quando a bandeira é identificada, a quantidade de dígitos também deve ser uma das emitidas por ela
 impl Validador for CartaoCredito<'_> {
-    fn is_valid(numero: &str) -> bool {
-        let mut numbers = somente_digitos(numero, numero.len());
-        numbers.reverse();
-        let mut is_odd: bool = true;
-        let mut odd_sum: u32 = 0;
-        let mut even_sum: u32 = 0;
-        for digit in numbers {
-            if is_odd {
-                odd_sum += digit;
-            } else {
-                even_sum += digit / 5 + (2 * digit) % 10;
+    fn verificar_com(numero: &str, _politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        verificar_caracteres(numero)?;
+        let mut digitos = verificar_quantidade(numero, 12, 19)?;
+        if let Some(bandeira) = Bandeira::detectar(numero) {
+            if !bandeira.tamanhos().contains(&digitos.len()) {
+                return Err(ErroValidacao::QuantidadeDigitosBandeira {
+                    bandeira,
+                    encontrado: digitos.len(),
+                });
             }
-            is_odd = !is_odd
         }
+        let dv = digitos.split_off(digitos.len() - 1);
 
-        (odd_sum + even_sum) % 10 == 0
+        verificar_dv(Self::calcular_dv(&digitos)?, dv)
     }
 
     fn validar(&self) -> bool {
@@ -84,20 +133,13 @@ impl Validador for CartaoCredito<'_> {
 }
 
 impl Validador for TituloEleitor<'_> {
-    fn is_valid(numero: &str) -> bool {
-        let multiplicadores1 = vec![2, 3, 4, 5, 6, 7, 8, 9];
-        let digitos = somente_digitos(numero, 12);
-        let part1 = digitos[0..8].to_vec();
-        let dv1 = &digitos[10];
-        let multiplicadores2 = vec![7, 8, 9];
-        let part2 = digitos[8..11].to_vec();
-        let dv2 = &digitos[11];
+    fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        verificar_caracteres(numero)?;
+        let mut digitos = verificar_quantidade(numero, 12, 12)?;
+        politica.verificar(&digitos)?;
+        let dv = digitos.split_off(10);
 
-        let digitos_verificadores: (u32, u32) = (
-            calc_digito_mod11(part1, multiplicadores1),
-            calc_digito_mod11(part2, multiplicadores2),
-        );
-        digitos_verificadores == (*dv1, *dv2)
+        verificar_dv(Self::calcular_dv(&digitos)?, dv)
     }
 
     fn validar(&self) -> bool {
@@ -106,36 +148,13 @@ impl Validador for TituloEleitor<'_> {
 }
 
 impl Validador for Cnh<'_> {
-    fn is_valid(numero: &str) -> bool {
-        let multiplicadores1 = vec![9, 8, 7, 6, 5, 4, 3, 2, 1];
-        let digitos = somente_digitos(numero, 11);
-        let part1 = digitos[0..9].to_vec();
-        let dv1 = &digitos[9];
-        let multiplicadores2 = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-        let part2 = digitos[0..9].to_vec();
-        let dv2 = &digitos[10];
-
-        let mut verifica1 = calc_digito(part1, multiplicadores1, |x| x);
-        let delta: u32 = if verifica1 % 11 == 10 { 2 } else { 0 };
-        verifica1 = mod_11(verifica1) as u32;
-
-        let verificador_final = |soma: u32| {
-            let mod11 = soma % 11;
-            let res = match Some(mod11 - delta) {
-                Some(x) => x,
-                None => 11 + mod11 - delta,
-            };
-            if res > 9 {
-                0
-            } else {
-                res
-            }
-        };
-
-        let mut verifica2 = calc_digito(part2, multiplicadores2, |x| x);
-        verifica2 = verificador_final(verifica2);
+    fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        verificar_caracteres(numero)?;
+        let mut digitos = verificar_quantidade(numero, 11, 11)?;
+        politica.verificar(&digitos)?;
+        let dv = digitos.split_off(9);
 
-        (verifica1, verifica2) == (*dv1, *dv2)
+        verificar_dv(Self::calcular_dv(&digitos)?, dv)
     }
 
     fn validar(&self) -> bool {
@@ -144,13 +163,13 @@ impl Validador for Cnh<'_> {
 }
 
 impl Validador for Renavam<'_> {
-    fn is_valid(numero: &str) -> bool {
-        let multiplicadores1 = vec![3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
-        let mut digitos = somente_digitos(numero, 11);
-        completa_esquerda(&mut digitos, 11);
-        let dv1 = digitos.split_off(10);
+    /// Não verifica os caracteres, pois o Renavam pode vir precedido da UF, como em `MG48.101 477-2`
+    fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        let mut digitos = verificar_quantidade(numero, 9, 11)?;
+        politica.verificar(&digitos)?;
+        let dv = digitos.split_off(digitos.len() - 1);
 
-        dv1[0] == calc_digito(digitos, multiplicadores1, onze_menos_mod11)
+        verificar_dv(Self::calcular_dv(&digitos)?, dv)
     }
 
     fn validar(&self) -> bool {
@@ -159,13 +178,12 @@ impl Validador for Renavam<'_> {
 }
 
 impl Validador for Rg<'_> {
-    fn is_valid(numero: &str) -> bool {
-        let multiplicadores1 = vec![2, 3, 4, 5, 6, 7, 8, 9];
-        let digitos = somente_digitos(numero, 9);
-        let part1 = digitos[0..8].to_vec();
-        let dv1 = &digitos[8];
-        let digitos_verificadores = calc_digito(part1, multiplicadores1, onze_menos_mod11);
-        digitos_verificadores == *dv1
+    fn verificar_com(numero: &str, _politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        verificar_caracteres(numero)?;
+        let mut digitos = verificar_quantidade(numero, 9, 9)?;
+        let dv = digitos.split_off(8);
+
+        verificar_dv(Self::calcular_dv(&digitos)?, dv)
     }
 
     fn validar(&self) -> bool {
@@ -174,45 +192,22 @@ impl Validador for Rg<'_> {
 }
 
 impl Validador for Cns<'_> {
-    fn is_valid(numero: &str) -> bool {
-        fn valida_1_2(numero: &str) -> bool {
-            let multiplicadores1 = vec![15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5];
-            let mut digitos = somente_digitos(numero, 15);
-            let dv = digitos.split_off(11);
-            let mut soma = calc_digito(digitos, multiplicadores1, |x| x);
-            let resto = soma % 11;
-            let calculo = 11 - resto;
-            let mut digito_anterior = 0;
-            let calculo = if calculo == 11 {
-                0
-            } else if calculo == 10 {
-                soma += 2;
-                let resto = soma % 11;
-                digito_anterior += 1;
-                11 - resto
-            } else {
-                calculo
-            };
-            println!("{:?}", calculo);
-
-            //retorno
-            dv == vec![0, 0, digito_anterior, calculo]
-        }
-        fn valida_7_8_9(numero: &str) -> bool {
-            let multiplicadores1 = vec![15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1];
-            let mut digitos = somente_digitos(numero, 15);
-
-            completa_esquerda(&mut digitos, 15);
-            let calculo = calc_digito(digitos, multiplicadores1, |x| x % 11);
-            calculo == 0
-        }
+    /// Cartões iniciados em 1 ou 2 terminam com 4 dígitos calculados; os iniciados em 7, 8 ou 9 com apenas 1
+    fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        verificar_caracteres(numero)?;
+        let mut digitos = verificar_quantidade(numero, 15, 15)?;
+        politica.verificar(&digitos)?;
+        let dv = match digitos[0] {
+            1 | 2 => digitos.split_off(11),
+            7..=9 => digitos.split_off(14),
+            primeiro => {
+                return Err(ErroValidacao::PrefixoInvalido {
+                    encontrado: primeiro.to_string(),
+                })
+            }
+        };
 
-        let primeiro_digito = somente_digitos(numero, 1);
-        match primeiro_digito.first() {
-            Some(1) | Some(2) => valida_1_2(numero),
-            Some(7) | Some(8) | Some(9) => valida_7_8_9(numero),
-            _ => false,
-        }
+        verificar_dv(Self::calcular_dv(&digitos)?, dv)
     }
 
     fn validar(&self) -> bool {
@@ -231,14 +226,12 @@ impl Validador for TipoCns<'_> {
 }
 
 impl Validador for CodigoBarrasGs1<'_> {
-    fn is_valid(numero: &str) -> bool {
-        let multiplicadores1 = vec![3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3];
-        let mut digitos = somente_digitos(numero, 18);
-        completa_esquerda(&mut digitos, 18);
-        let dv1 = &digitos.split_off(17);
-        let digitos_verificadores =
-            calc_digito(digitos, multiplicadores1, |x| (x / 10 + 1) * 10 - x);
-        digitos_verificadores == dv1[0]
+    fn verificar_com(numero: &str, _politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        verificar_caracteres(numero)?;
+        let mut digitos = verificar_quantidade(numero, 8, 18)?;
+        let dv = digitos.split_off(digitos.len() - 1);
+
+        verificar_dv(Self::calcular_dv(&digitos)?, dv)
     }
 
     fn validar(&self) -> bool {
@@ -247,15 +240,71 @@ impl Validador for CodigoBarrasGs1<'_> {
 }
 
 impl Validador for Pis<'_> {
-    fn is_valid(numero: &str) -> bool {
-        let multiplicadores1 = vec![3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
-        let mut digitos = somente_digitos(numero, 11);
-        let dv1 = digitos.split_off(10);
+    fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        verificar_caracteres(numero)?;
+        let mut digitos = verificar_quantidade(numero, 11, 11)?;
+        politica.verificar(&digitos)?;
+        let dv = digitos.split_off(10);
 
-        dv1[0] == calc_digito(digitos, multiplicadores1, onze_menos_mod11)
+        verificar_dv(Self::calcular_dv(&digitos)?, dv)
     }
 
     fn validar(&self) -> bool {
         Self::is_valid(self.0)
     }
 }
+
+/// Aceita a linha digitável ou o código de barras; na linha digitável também são verificados os dígitos de cada campo
+impl Validador for Boleto<'_> {
+    fn verificar_com(numero: &str, _politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        Self::digitos_barras(numero).map(|_| ())
+    }
+
+    fn validar(&self) -> bool {
+        Self::is_valid(self.0)
+    }
+}
+
+/// Aceita a linha digitável ou o código de barras; o identificador de valor define o módulo de todos os dígitos verificadores
+impl Validador for Arrecadacao<'_> {
+    fn verificar_com(numero: &str, _politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        Self::digitos_barras(numero).map(|_| ())
+    }
+
+    fn validar(&self) -> bool {
+        Self::is_valid(self.0)
+    }
+}
+
+/// Além do dígito verificador, a UF, o mês, o modelo e o CNPJ ou CPF do emitente devem ser válidos
+impl Validador for ChaveAcesso<'_> {
+    fn verificar_com(numero: &str, _politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        ChaveAcesso(numero).partes().map(|_| ())
+    }
+
+    fn validar(&self) -> bool {
+        Self::is_valid(self.0)
+    }
+}
+
+/// Sem a UF, o número deve vir precedido da sigla do estado, como em `SP 110.042.490.114`
+impl Validador for IeUf<'_> {
+    fn verificar_com(numero: &str, _politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        let (uf, numero) = separar_uf(numero)?;
+        verificar_ie(uf, numero)
+    }
+
+    fn validar(&self) -> bool {
+        verificar_ie(self.0, self.1).is_ok()
+    }
+}
+
+impl Validador for Ie<'_> {
+    fn verificar_com(numero: &str, politica: PoliticaRepeticao) -> Result<(), ErroValidacao> {
+        IeUf::verificar_com(numero, politica)
+    }
+
+    fn validar(&self) -> bool {
+        self.0.validar()
+    }
+}
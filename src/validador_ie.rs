@@ -1 +1,376 @@
-pub struct IeUf<'data>(pub &'data str);
+//! Validação da Inscrição Estadual, cujo algoritmo varia de estado para estado
+//! Cada UF define seus próprios pesos, tamanho e prefixos, conforme o roteiro de crítica do Sintegra
+//!```rust
+//! # use validador_br::types::Uf;
+//! # use validador_br::validador_ie::{verificar_ie, IeUf};
+//! # use validador_br::validador::Validador;
+//! assert!(verificar_ie(Uf::SP, "110.042.490.114").is_ok());
+//! assert!(IeUf(Uf::MG, "062.307.904/0081").validar());
+//! assert!(IeUf(Uf::SP, "P-01100424.3/002").validar());
+//!```
+
+use crate::erro::ErroValidacao;
+use crate::funcoes::{calc_digito, completa_esquerda, mod_11, onze_menos_mod11,
+    verificar_caracteres, verificar_quantidade,
+};
+use crate::types::Uf;
+use crate::validador::verificar_dv;
+
+/// Inscrição Estadual acompanhada da UF que a emitiu
+pub struct IeUf<'data>(pub Uf, pub &'data str);
+
+/// Contribuintes dispensados da inscrição informam `ISENTO` no lugar do número
+pub const ISENTO: &str = "ISENTO";
+
+/// Verifica se o contribuinte informou `ISENTO`, sem diferenciar maiúsculas e minúsculas
+pub fn is_isento(numero: &str) -> bool {
+    numero.trim().eq_ignore_ascii_case(ISENTO)
+}
+
+/// Verifica a Inscrição Estadual de acordo com as regras da UF
+/// `ISENTO` é aceito para qualquer UF
+pub fn verificar_ie(uf: Uf, numero: &str) -> Result<(), ErroValidacao> {
+    if is_isento(numero) {
+        return Ok(());
+    }
+    if uf == Uf::SP {
+        if let Some(rural) = numero.trim_start().strip_prefix(['P', 'p']) {
+            return verificar_sp_produtor_rural(rural);
+        }
+    }
+    verificar_caracteres(numero)?;
+
+    let tamanhos = tamanhos(uf);
+    let (minimo, maximo) = (tamanhos[0], tamanhos[tamanhos.len() - 1]);
+    let digitos = verificar_quantidade(numero, minimo, maximo)?;
+    if !tamanhos.contains(&digitos.len()) {
+        return Err(ErroValidacao::QuantidadeDigitos {
+            minimo,
+            maximo,
+            encontrado: digitos.len(),
+        });
+    }
+    let posicoes = posicoes_dv(uf, digitos.len());
+    let (base, dv): (Vec<_>, Vec<_>) = digitos
+        .iter()
+        .enumerate()
+        .partition(|(index, _)| !posicoes.contains(index));
+    let base: Vec<u32> = base.into_iter().map(|(_, d)| *d).collect();
+    let dv: Vec<u32> = dv.into_iter().map(|(_, d)| *d).collect();
+    verificar_dv(calcular(uf, &base)?, dv)
+}
+
+/// Calcula os dígitos verificadores da Inscrição Estadual a partir da base, o número sem os dígitos verificadores
+/// Os dígitos são retornados na ordem em que aparecem no número; em SP ocupam a 9ª e a 12ª posições
+///```rust
+/// # use validador_br::types::Uf;
+/// # use validador_br::validador_ie::calcular_dv_ie;
+/// assert_eq!(calcular_dv_ie(Uf::SP, "1100424911"), Ok(vec![0, 4]));
+/// assert_eq!(calcular_dv_ie(Uf::MG, "06230790400"), Ok(vec![8, 1]));
+///```
+pub fn calcular_dv_ie(uf: Uf, base: &str) -> Result<Vec<u32>, ErroValidacao> {
+    verificar_caracteres(base)?;
+    let tamanhos: Vec<usize> = tamanhos(uf)
+        .iter()
+        .map(|tamanho| tamanho - posicoes_dv(uf, *tamanho).len())
+        .collect();
+    let (minimo, maximo) = (tamanhos[0], tamanhos[tamanhos.len() - 1]);
+    let base = verificar_quantidade(base, minimo, maximo)?;
+    if !tamanhos.contains(&base.len()) {
+        return Err(ErroValidacao::QuantidadeDigitos {
+            minimo,
+            maximo,
+            encontrado: base.len(),
+        });
+    }
+    calcular(uf, &base)
+}
+
+/// Separa a sigla da UF do número, como em `SP 110.042.490.114`
+pub fn separar_uf(numero: &str) -> Result<(Uf, &str), ErroValidacao> {
+    let numero = numero.trim_start();
+    let sigla = numero.get(0..2).unwrap_or(numero);
+    match Uf::from_sigla(sigla) {
+        Some(uf) => Ok((uf, &numero[2..])),
+        None => Err(ErroValidacao::UfInvalida {
+            encontrado: sigla.to_string(),
+        }),
+    }
+}
+
+/// Quantidades de dígitos aceitas pela UF, incluindo os dígitos verificadores
+fn tamanhos(uf: Uf) -> &'static [usize] {
+    match uf {
+        Uf::AC | Uf::DF | Uf::MG => &[13],
+        Uf::BA => &[8, 9],
+        Uf::MT => &[9, 10, 11],
+        Uf::PR | Uf::RS => &[10],
+        Uf::PE | Uf::RO => &[9, 14],
+        Uf::RJ => &[8],
+        Uf::RN => &[9, 10],
+        Uf::SP => &[12],
+        Uf::TO => &[9, 11],
+        _ => &[9],
+    }
+}
+
+/// Posições dos dígitos verificadores no número completo
+fn posicoes_dv(uf: Uf, tamanho: usize) -> Vec<usize> {
+    match uf {
+        Uf::SP => vec![8, 11],
+        Uf::AC | Uf::DF | Uf::BA | Uf::MG | Uf::PR => vec![tamanho - 2, tamanho - 1],
+        Uf::PE if tamanho == 9 => vec![7, 8],
+        _ => vec![tamanho - 1],
+    }
+}
+
+fn calcular(uf: Uf, base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+    match uf {
+        Uf::AC => dv_ac_df(base, "01"),
+        Uf::AL => dv_al(base),
+        Uf::AP => dv_ap(base),
+        Uf::AM => dv_mod11(base, &[]),
+        Uf::BA => Ok(dv_ba(base)),
+        Uf::CE => dv_mod11(base, &[]),
+        Uf::DF => dv_ac_df(base, "07"),
+        Uf::ES => dv_mod11(base, &[]),
+        Uf::GO => dv_go(base),
+        Uf::MA => dv_mod11(base, &["12"]),
+        Uf::MT => Ok(dv_mt(base)),
+        Uf::MS => dv_mod11(base, &["28", "50"]),
+        Uf::MG => Ok(dv_mg(base)),
+        Uf::PA => dv_mod11(base, &["15"]),
+        Uf::PB => dv_mod11(base, &[]),
+        Uf::PR => Ok(dv_pr(base)),
+        Uf::PE => Ok(dv_pe(base)),
+        Uf::PI => dv_mod11(base, &["19"]),
+        Uf::RJ => Ok(dv_rj(base)),
+        Uf::RN => dv_rn(base),
+        Uf::RS => Ok(dv_rs(base)),
+        Uf::RO => Ok(dv_ro(base)),
+        Uf::RR => dv_rr(base),
+        Uf::SC => dv_mod11(base, &[]),
+        Uf::SP => Ok(dv_sp(base)),
+        Uf::SE => dv_mod11(base, &[]),
+        Uf::TO => dv_to(base),
+    }
+}
+
+fn calc(digitos: &[u32], pesos: &[u32], funcao_modulo: fn(x: u32) -> u32) -> u32 {
+    calc_digito(digitos.to_vec(), pesos.to_vec(), funcao_modulo)
+}
+
+/// Pesos decrescentes, `pesos_ate_2(9) == [9, 8, 7, 6, 5, 4, 3, 2]`
+fn pesos_ate_2(maior: u32) -> Vec<u32> {
+    (2..=maior).rev().collect()
+}
+
+fn verificar_prefixo(digitos: &[u32], permitidos: &[&str]) -> Result<(), ErroValidacao> {
+    let texto: String = digitos.iter().map(|d| d.to_string()).collect();
+    if permitidos.is_empty() || permitidos.iter().any(|p| texto.starts_with(p)) {
+        Ok(())
+    } else {
+        Err(ErroValidacao::PrefixoInvalido {
+            encontrado: texto.chars().take(2).collect(),
+        })
+    }
+}
+
+/// Módulo 10 usado pela Bahia: 10 - resto, sendo resto 0 => 0
+fn dez_menos_mod10(value: u32) -> u32 {
+    (10 - value % 10) % 10
+}
+
+/// Rondônia e Pernambuco (formato antigo) subtraem 10 quando o resultado tem dois dígitos
+fn onze_menos_mod11_subtrai_10(value: u32) -> u32 {
+    let res = 11 - value % 11;
+    if res > 9 {
+        res - 10
+    } else {
+        res
+    }
+}
+
+/// AM, CE, ES, MA, MS, PA, PB, PI, SC, SE: 8 dígitos, pesos 9..2 e 11 - mod11
+fn dv_mod11(base: &[u32], prefixos: &[&str]) -> Result<Vec<u32>, ErroValidacao> {
+    verificar_prefixo(base, prefixos)?;
+    Ok(vec![calc(base, &pesos_ate_2(9), onze_menos_mod11)])
+}
+
+/// Acre e Distrito Federal: 13 dígitos e dois dígitos verificadores
+fn dv_ac_df(base: &[u32], prefixo: &str) -> Result<Vec<u32>, ErroValidacao> {
+    verificar_prefixo(base, &[prefixo])?;
+    let mut digitos = base.to_vec();
+    let dv1 = calc(&digitos, &[4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2], onze_menos_mod11);
+    digitos.push(dv1);
+    let dv2 = calc(&digitos, &[5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2], onze_menos_mod11);
+    Ok(vec![dv1, dv2])
+}
+
+/// Alagoas usa o mesmo cálculo do CPF, módulo 11 de (soma * 10)
+fn dv_al(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+    verificar_prefixo(base, &["24"])?;
+    let decima = |x: u32| mod_11(10 * x) as u32;
+    Ok(vec![calc(base, &pesos_ate_2(9), decima)])
+}
+
+/// Amapá soma uma constante `p` e usa `d` quando o resultado é 11, de acordo com a faixa do número
+fn dv_ap(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+    verificar_prefixo(base, &["03"])?;
+    let faixa = base.iter().fold(0, |acc, d| acc * 10 + d);
+    let (p, d) = match faixa {
+        3_000_001..=3_017_000 => (5, 0),
+        3_017_001..=3_019_022 => (9, 1),
+        _ => (0, 0),
+    };
+    let soma = p + calc(base, &pesos_ate_2(9), |x| x);
+    let calculo = match 11 - soma % 11 {
+        10 => 0,
+        11 => d,
+        res => res,
+    };
+    Ok(vec![calculo])
+}
+
+/// Bahia alterna entre módulo 10 e módulo 11 de acordo com o primeiro dígito (8 dígitos) ou o segundo (9 dígitos)
+/// O segundo dígito verificador é calculado antes do primeiro
+fn dv_ba(base: &[u32]) -> Vec<u32> {
+    let seletor = if base.len() == 6 { base[0] } else { base[1] };
+    let funcao: fn(u32) -> u32 = match seletor {
+        6 | 7 | 9 => onze_menos_mod11,
+        _ => dez_menos_mod10,
+    };
+    let maior = base.len() as u32 + 1;
+    let mut digitos = base.to_vec();
+    let dv2 = calc(&digitos, &pesos_ate_2(maior), funcao);
+    digitos.push(dv2);
+    let dv1 = calc(&digitos, &pesos_ate_2(maior + 1), funcao);
+    vec![dv1, dv2]
+}
+
+/// Goiás: resto 1 resulta em 1 apenas na faixa 10103105..=10119997
+fn dv_go(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+    verificar_prefixo(
+        base,
+        &["10", "11", "15", "20", "21", "22", "23", "24", "25", "26", "27", "28", "29"],
+    )?;
+    let faixa = base.iter().fold(0, |acc, d| acc * 10 + d);
+    let calculo = match calc(base, &pesos_ate_2(9), |x| x % 11) {
+        0 => 0,
+        1 if (10_103_105..=10_119_997).contains(&faixa) => 1,
+        1 => 0,
+        resto => 11 - resto,
+    };
+    Ok(vec![calculo])
+}
+
+/// Mato Grosso completa o número com zeros à esquerda até 11 dígitos
+fn dv_mt(base: &[u32]) -> Vec<u32> {
+    let mut digitos = base.to_vec();
+    completa_esquerda(&mut digitos, 10);
+    vec![calc(&digitos, &[3, 2, 9, 8, 7, 6, 5, 4, 3, 2], onze_menos_mod11)]
+}
+
+/// Minas Gerais: o primeiro dígito insere um zero após o código do município e soma os algarismos dos produtos por 1 e 2
+fn dv_mg(base: &[u32]) -> Vec<u32> {
+    let mut digitos = base.to_vec();
+    digitos.insert(3, 0);
+    let soma: u32 = digitos
+        .iter()
+        .enumerate()
+        .map(|(index, value)| value * (1 + index as u32 % 2))
+        .map(|produto| produto / 10 + produto % 10)
+        .sum();
+    let dv1 = dez_menos_mod10(soma);
+
+    let mut digitos = base.to_vec();
+    digitos.push(dv1);
+    let dv2 = calc(&digitos, &[3, 2, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2], onze_menos_mod11);
+    vec![dv1, dv2]
+}
+
+fn dv_pr(base: &[u32]) -> Vec<u32> {
+    let mut digitos = base.to_vec();
+    let dv1 = calc(&digitos, &[3, 2, 7, 6, 5, 4, 3, 2], onze_menos_mod11);
+    digitos.push(dv1);
+    let dv2 = calc(&digitos, &[4, 3, 2, 7, 6, 5, 4, 3, 2], onze_menos_mod11);
+    vec![dv1, dv2]
+}
+
+/// Pernambuco: formato eFisco com 9 dígitos ou o antigo CACEPE com 14 dígitos
+fn dv_pe(base: &[u32]) -> Vec<u32> {
+    if base.len() == 7 {
+        let mut digitos = base.to_vec();
+        let dv1 = calc(&digitos, &pesos_ate_2(8), onze_menos_mod11);
+        digitos.push(dv1);
+        let dv2 = calc(&digitos, &pesos_ate_2(9), onze_menos_mod11);
+        vec![dv1, dv2]
+    } else {
+        let pesos = [5, 4, 3, 2, 1, 9, 8, 7, 6, 5, 4, 3, 2];
+        vec![calc(base, &pesos, onze_menos_mod11_subtrai_10)]
+    }
+}
+
+fn dv_rj(base: &[u32]) -> Vec<u32> {
+    vec![calc(base, &[2, 7, 6, 5, 4, 3, 2], onze_menos_mod11)]
+}
+
+/// Rio Grande do Norte: 9 ou 10 dígitos, módulo 11 de (soma * 10)
+fn dv_rn(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+    verificar_prefixo(base, &["20"])?;
+    let decima = |x: u32| mod_11(10 * x) as u32;
+    Ok(vec![calc(base, &pesos_ate_2(base.len() as u32 + 1), decima)])
+}
+
+fn dv_rs(base: &[u32]) -> Vec<u32> {
+    vec![calc(base, &[2, 9, 8, 7, 6, 5, 4, 3, 2], onze_menos_mod11)]
+}
+
+/// Rondônia: 14 dígitos desde 2000; no formato antigo de 9 dígitos os 3 primeiros (município) não entram no cálculo
+fn dv_ro(base: &[u32]) -> Vec<u32> {
+    if base.len() == 8 {
+        vec![calc(&base[3..], &[6, 5, 4, 3, 2], onze_menos_mod11_subtrai_10)]
+    } else {
+        let pesos = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+        vec![calc(base, &pesos, onze_menos_mod11_subtrai_10)]
+    }
+}
+
+/// Roraima: pesos 1..8 e módulo 9
+fn dv_rr(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+    verificar_prefixo(base, &["24"])?;
+    Ok(vec![calc(base, &[1, 2, 3, 4, 5, 6, 7, 8], |x| x % 9)])
+}
+
+/// São Paulo (comércio e indústria): o primeiro dígito verificador é o 9º e o segundo o 12º
+fn dv_sp(base: &[u32]) -> Vec<u32> {
+    let resto_mod11 = |x: u32| x % 11 % 10;
+    let dv1 = calc(&base[0..8], &[1, 3, 4, 5, 6, 7, 8, 10], resto_mod11);
+    let mut digitos = base[0..8].to_vec();
+    digitos.push(dv1);
+    digitos.extend_from_slice(&base[8..10]);
+    let dv2 = calc(&digitos, &[3, 2, 10, 9, 8, 7, 6, 5, 4, 3, 2], resto_mod11);
+    vec![dv1, dv2]
+}
+
+/// São Paulo (produtor rural): `P-01100424.3/002`, com um dígito verificador na 9ª posição
+fn verificar_sp_produtor_rural(numero: &str) -> Result<(), ErroValidacao> {
+    verificar_caracteres(numero)?;
+    let digitos = verificar_quantidade(numero, 12, 12)?;
+    let dv1 = calc(&digitos[0..8], &[1, 3, 4, 5, 6, 7, 8, 10], |x| x % 11 % 10);
+    verificar_dv(vec![dv1], vec![digitos[8]])
+}
+
+/// Tocantins: 9 dígitos ou o formato antigo de 11 dígitos, cujo 3º e 4º dígitos (tipo de empresa) não entram no cálculo
+fn dv_to(base: &[u32]) -> Result<Vec<u32>, ErroValidacao> {
+    let mut digitos = base.to_vec();
+    if digitos.len() == 10 {
+        let tipo = digitos.drain(2..4).fold(0, |acc, d| acc * 10 + d);
+        if ![1, 2, 3, 99].contains(&tipo) {
+            return Err(ErroValidacao::PrefixoInvalido {
+                encontrado: format!("{tipo:02}"),
+            });
+        }
+    }
+    Ok(vec![calc(&digitos, &pesos_ate_2(9), onze_menos_mod11)])
+}
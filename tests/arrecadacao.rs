@@ -0,0 +1,134 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::formatar::Formatar;
+use validador_br::types::{Arrecadacao, Segmento};
+use validador_br::validador::Validador;
+
+const LINHA: &str = "83640000001-1 33120138000-2 81288462711-6 08013618155-1";
+const BARRAS: &str = "83640000001331201380008128846271108013618155";
+
+/// Guia de órgão governamental com valor efetivo e dígitos pelo módulo 11
+const LINHA_GOVERNO: &str = "85890000460-9 52460179160-5 60759305086-5 83148300001-0";
+const BARRAS_GOVERNO: &str = "85890000460524601791606075930508683148300001";
+
+/// A guia de órgão governamental com o identificador 9, valor de referência pelo módulo 11
+/// Os dígitos verificadores foram calculados à parte pela regra do módulo 11 da FEBRABAN
+const LINHA_REFERENCIA_11: &str = "85970000460-3 52460179160-5 60759305086-5 83148300001-0";
+const BARRAS_REFERENCIA_11: &str = "85970000460524601791606075930508683148300001";
+
+/// Guia de prefeitura com valor de referência
+const LINHA_PREFEITURA: &str = "81770000000-0 01093659970-2 41131079703-9 00143370831-8";
+const BARRAS_PREFEITURA: &str = "81770000000010936599704113107970300143370831";
+
+#[test]
+fn test_arrecadacao() {
+    assert!(Arrecadacao::is_valid(LINHA));
+    assert!(Arrecadacao::is_valid(BARRAS));
+    assert_eq!(Arrecadacao(LINHA).codigo_barras().unwrap(), BARRAS);
+    assert_eq!(Arrecadacao(BARRAS).linha_digitavel().unwrap(), Arrecadacao(LINHA).canonico());
+    assert_eq!(Arrecadacao(LINHA).formatar(), LINHA);
+    assert_eq!(Arrecadacao(BARRAS).formatar(), BARRAS);
+
+    let conta = Arrecadacao(BARRAS);
+    assert_eq!(conta.segmento(), Ok(Segmento::EnergiaGas));
+    assert_eq!(conta.valor_efetivo(), Ok(true));
+    assert_eq!(conta.valor(), Ok(13312));
+    assert_eq!(conta.empresa(), Ok("0138".to_string()));
+    assert_eq!(conta.campo_livre(), Ok("0008128846271108013618155".to_string()));
+}
+
+#[test]
+fn test_modulo_11() {
+    // identificador 8: valor efetivo, dígitos pelo módulo 11
+    assert!(Arrecadacao::is_valid(LINHA_GOVERNO));
+    assert!(Arrecadacao::is_valid(BARRAS_GOVERNO));
+    assert_eq!(Arrecadacao(LINHA_GOVERNO).codigo_barras().unwrap(), BARRAS_GOVERNO);
+    assert_eq!(Arrecadacao(BARRAS_GOVERNO).linha_digitavel().unwrap(), Arrecadacao(LINHA_GOVERNO).canonico());
+    assert_eq!(Arrecadacao(BARRAS_GOVERNO).formatar(), BARRAS_GOVERNO);
+
+    let guia = Arrecadacao(LINHA_GOVERNO);
+    assert_eq!(guia.segmento(), Ok(Segmento::OrgaosGovernamentais));
+    assert_eq!(guia.valor_efetivo(), Ok(true));
+    assert_eq!(guia.valor(), Ok(4605246));
+    assert_eq!(guia.empresa(), Ok("0179".to_string()));
+    assert_eq!(guia.campo_livre(), Ok("1606075930508683148300001".to_string()));
+    assert!(matches!(
+        Arrecadacao::verificar("85890000460-9 52460179160-5 60759305086-5 83148300002-0"),
+        Err(ErroValidacao::DigitoVerificador { .. })
+    ));
+}
+
+#[test]
+fn test_valor_referencia() {
+    // identificador 7: valor de referência, dígitos pelo módulo 10
+    assert!(Arrecadacao::is_valid(LINHA_PREFEITURA));
+    assert_eq!(Arrecadacao(LINHA_PREFEITURA).codigo_barras().unwrap(), BARRAS_PREFEITURA);
+    let linha = Arrecadacao(BARRAS_PREFEITURA).linha_digitavel().unwrap();
+    assert_eq!(Arrecadacao(&linha).formatar(), LINHA_PREFEITURA);
+
+    let guia = Arrecadacao(BARRAS_PREFEITURA);
+    assert_eq!(guia.segmento(), Ok(Segmento::Prefeituras));
+    assert_eq!(guia.valor_efetivo(), Ok(false));
+    assert_eq!(guia.valor(), Ok(109));
+    assert_eq!(guia.empresa(), Ok("3659".to_string()));
+}
+
+#[test]
+fn test_valor_referencia_modulo_11() {
+    // identificador 9: valor de referência, dígitos pelo módulo 11
+    assert!(Arrecadacao::is_valid(LINHA_REFERENCIA_11));
+    assert_eq!(Arrecadacao(LINHA_REFERENCIA_11).codigo_barras().unwrap(), BARRAS_REFERENCIA_11);
+    assert_eq!(Arrecadacao(BARRAS_REFERENCIA_11).linha_digitavel().unwrap(), Arrecadacao(LINHA_REFERENCIA_11).canonico());
+
+    let guia = Arrecadacao(BARRAS_REFERENCIA_11);
+    assert_eq!(guia.valor_efetivo(), Ok(false));
+    assert_eq!(guia.valor(), Ok(4605246));
+    // o dígito geral da guia original, calculado com o identificador 8, não vale com o 9
+    assert!(matches!(
+        Arrecadacao::verificar("85990000460524601791606075930508683148300001"),
+        Err(ErroValidacao::DigitoVerificador { .. })
+    ));
+}
+
+#[test]
+fn test_arrecadacao_invalida() {
+    // dígito do segundo bloco
+    assert_eq!(
+        Arrecadacao::verificar("83640000001-1 33120138000-3 81288462711-6 08013618155-1"),
+        Err(ErroValidacao::DigitoVerificador { esperado: vec![2], encontrado: vec![3] })
+    );
+    // dígito geral
+    assert!(matches!(
+        Arrecadacao::verificar("83650000001331201380008128846271108013618155"),
+        Err(ErroValidacao::DigitoVerificador { .. })
+    ));
+    // boletos bancários não começam com 8
+    assert_eq!(
+        Arrecadacao::verificar("00193373700000001000500940144816060680935031"),
+        Err(ErroValidacao::PrefixoInvalido { encontrado: "00".to_string() })
+    );
+    // segmento 0 não existe
+    assert_eq!(
+        Arrecadacao::verificar("80640000001331201380008128846271108013618155"),
+        Err(ErroValidacao::PrefixoInvalido { encontrado: "80".to_string() })
+    );
+    // identificador de valor 5 não existe
+    assert_eq!(
+        Arrecadacao::verificar("83540000001331201380008128846271108013618155"),
+        Err(ErroValidacao::PrefixoInvalido { encontrado: "835".to_string() })
+    );
+    assert_eq!(
+        Arrecadacao::verificar("00190500954014481606906809350314337370000000100"),
+        Err(ErroValidacao::QuantidadeDigitos { minimo: 44, maximo: 48, encontrado: 47 })
+    );
+}
+
+#[test]
+fn test_segmento() {
+    for segmento in Segmento::TODOS {
+        assert_eq!(Segmento::from_digito(segmento.digito()), Some(segmento));
+    }
+    assert_eq!(Segmento::from_digito(0), None);
+    assert_eq!(Segmento::from_digito(8), None);
+    assert_eq!(Segmento::UsoBanco.digito(), 9);
+    assert_eq!(Segmento::EnergiaGas.to_string(), "Energia elétrica e gás");
+}
@@ -0,0 +1,56 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::owned::CartaoCreditoBuf;
+use validador_br::types::{Bandeira, CartaoCredito};
+use validador_br::validador::Validador;
+
+#[test]
+fn test_bandeira() {
+    let cartoes = [
+        ("4111 1111 1111 1111", Bandeira::Visa),
+        ("4222222222222", Bandeira::Visa),
+        ("5555 5555 5555 4444", Bandeira::Mastercard),
+        ("2221 0000 0000 0009", Bandeira::Mastercard),
+        ("3782 822463 10005", Bandeira::Amex),
+        ("3056 930902 5904", Bandeira::Diners),
+        ("6011 1111 1111 1117", Bandeira::Discover),
+        ("3530 1113 3330 0000", Bandeira::Jcb),
+        ("6362 9700 0045 7013", Bandeira::Elo),
+        ("5090 0000 0000 0000", Bandeira::Elo),
+        ("6062 8256 2425 4001", Bandeira::Hipercard),
+        ("3841 0000 0000 0007", Bandeira::Hipercard),
+    ];
+    for (numero, bandeira) in cartoes {
+        assert_eq!(CartaoCredito(numero).bandeira(), Some(bandeira), "{numero}");
+        assert!(CartaoCredito::is_valid(numero), "{numero}");
+        assert_eq!(CartaoCreditoBuf::try_from(numero).unwrap().bandeira(), Some(bandeira));
+    }
+
+    // Faixas Elo dentro das faixas de Visa e Discover
+    assert_eq!(Bandeira::detectar("4312 74"), Some(Bandeira::Elo));
+    assert_eq!(Bandeira::detectar("6500 31"), Some(Bandeira::Elo));
+    assert_eq!(Bandeira::detectar("6500 34"), Some(Bandeira::Discover));
+    assert_eq!(Bandeira::detectar("2014 4799698 0942"), None);
+    assert_eq!(Bandeira::detectar("4111"), None);
+    assert_eq!(Bandeira::Amex.to_string(), "American Express");
+}
+
+#[test]
+fn test_bandeira_quantidade() {
+    assert_eq!(
+        CartaoCredito::verificar("411111111111111"),
+        Err(ErroValidacao::QuantidadeDigitosBandeira {
+            bandeira: Bandeira::Visa,
+            encontrado: 15,
+        })
+    );
+    assert_eq!(
+        CartaoCredito::verificar("3782 8224 6310 0050"),
+        Err(ErroValidacao::QuantidadeDigitosBandeira {
+            bandeira: Bandeira::Amex,
+            encontrado: 16,
+        })
+    );
+    // Bandeiras desconhecidas são validadas apenas pelo algoritmo de Luhn
+    assert!(CartaoCredito::is_valid("2014 4799698 0942"));
+    assert!(!CartaoCredito::is_valid("4111 1111 1111 1112"));
+}
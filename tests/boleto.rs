@@ -0,0 +1,108 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::formatar::Formatar;
+use validador_br::types::{Boleto, Data};
+use validador_br::validador::Validador;
+
+const LINHA: &str = "00190.50095 40144.816069 06809.350314 3 37370000000100";
+const BARRAS: &str = "00193373700000001000500940144816060680935031";
+
+/// Boleto do banco 212 com vencimento em 16/07/2018 e valor de R$ 20,00
+const LINHA_212: &str = "21290.00119 21100.012109 04475.617405 9 75870000002000";
+const BARRAS_212: &str = "21299758700000020000001121100012100447561740";
+
+#[test]
+fn test_boleto() {
+    assert!(Boleto::is_valid(LINHA));
+    assert!(Boleto::is_valid(BARRAS));
+    assert!(Boleto::is_valid("00190500954014481606906809350314337370000000100"));
+    assert_eq!(Boleto(LINHA).codigo_barras().unwrap(), BARRAS);
+    assert_eq!(Boleto(BARRAS).linha_digitavel().unwrap(), Boleto(LINHA).canonico());
+    assert_eq!(Boleto(BARRAS).codigo_barras().unwrap(), BARRAS);
+    assert_eq!(Boleto(LINHA).formatar(), LINHA);
+    assert_eq!(Boleto(BARRAS).formatar(), BARRAS);
+
+    let boleto = Boleto(LINHA);
+    assert_eq!(boleto.banco(), Ok("001".to_string()));
+    assert_eq!(boleto.moeda(), Ok(9));
+    assert_eq!(boleto.fator_vencimento(), Ok(Some(3737)));
+    assert_eq!(boleto.valor(), Ok(100));
+    assert_eq!(boleto.campo_livre(), Ok("0500940144816060680935031".to_string()));
+}
+
+#[test]
+fn test_boleto_invalido() {
+    // dígito do primeiro campo
+    assert_eq!(
+        Boleto::verificar("00190.50096 40144.816069 06809.350314 3 37370000000100"),
+        Err(ErroValidacao::DigitoVerificador { esperado: vec![5], encontrado: vec![6] })
+    );
+    // dígito do terceiro campo
+    assert!(matches!(
+        Boleto::verificar("00190.50095 40144.816069 06809.350315 3 37370000000100"),
+        Err(ErroValidacao::DigitoVerificador { .. })
+    ));
+    // dígito geral
+    assert_eq!(
+        Boleto::verificar("00190.50095 40144.816069 06809.350314 4 37370000000100"),
+        Err(ErroValidacao::DigitoVerificador { esperado: vec![3], encontrado: vec![4] })
+    );
+    assert_eq!(
+        Boleto::verificar("00194373700000001000500940144816060680935031"),
+        Err(ErroValidacao::DigitoVerificador { esperado: vec![3], encontrado: vec![4] })
+    );
+    assert_eq!(
+        Boleto::verificar("0019337370000000100050094014481606068093503"),
+        Err(ErroValidacao::QuantidadeDigitos { minimo: 44, maximo: 47, encontrado: 43 })
+    );
+    assert_eq!(
+        Boleto::verificar("001933737000000010005009401448160606809350310"),
+        Err(ErroValidacao::QuantidadeDigitos { minimo: 44, maximo: 47, encontrado: 45 })
+    );
+    assert!(Boleto::try_from("00190.50095 40144.816069 06809.350314 3 3737000000010O").is_err());
+}
+
+#[test]
+fn test_fator_vencimento() {
+    let data = |ano, mes, dia| Data::new(ano, mes, dia);
+
+    // emitidos antes do reinício do fator em 22/02/2025
+    assert_eq!(Boleto(LINHA_212).fator_vencimento(), Ok(Some(7587)));
+    assert_eq!(Boleto(LINHA_212).vencimento_proximo(data(2018, 7, 1).unwrap()), Ok(data(2018, 7, 16)));
+    assert_eq!(Boleto(LINHA_212).vencimento_proximo(data(2030, 1, 1).unwrap()), Ok(data(2018, 7, 16)));
+    assert_eq!(Boleto(LINHA).vencimento_proximo(data(2007, 12, 1).unwrap()), Ok(data(2007, 12, 31)));
+
+    // no ciclo atual, os mesmos fatores vencem 9000 dias depois
+    assert_eq!(Boleto(LINHA_212).vencimento(), Ok(data(2043, 3, 7)));
+    assert_eq!(Boleto(LINHA_212).vencimento_proximo(data(2043, 1, 1).unwrap()), Ok(data(2043, 3, 7)));
+    assert_eq!(Boleto(LINHA).vencimento(), Ok(data(2032, 8, 21)));
+    assert_eq!(Boleto(BARRAS).vencimento_proximo(data(2032, 1, 1).unwrap()), Ok(data(2032, 8, 21)));
+    assert_eq!(Boleto(BARRAS).vencimento_proximo(data(1990, 1, 1).unwrap()), Ok(data(2007, 12, 31)));
+}
+
+#[test]
+fn test_conversao() {
+    assert!(Boleto::is_valid(LINHA_212));
+    assert!(Boleto::is_valid(BARRAS_212));
+    assert_eq!(Boleto(LINHA_212).codigo_barras().unwrap(), BARRAS_212);
+    assert_eq!(Boleto(BARRAS_212).linha_digitavel().unwrap(), Boleto(LINHA_212).canonico());
+    assert_eq!(Boleto(BARRAS_212).formatar(), BARRAS_212);
+    assert_eq!(Boleto(&Boleto(BARRAS_212).linha_digitavel().unwrap()).formatar(), LINHA_212);
+
+    let boleto = Boleto(BARRAS_212);
+    assert_eq!(boleto.banco(), Ok("212".to_string()));
+    assert_eq!(boleto.moeda(), Ok(9));
+    assert_eq!(boleto.valor(), Ok(2000));
+    assert_eq!(boleto.campo_livre(), Ok("0001121100012100447561740".to_string()));
+}
+
+#[test]
+fn test_data() {
+    assert_eq!(Data::new(2024, 2, 29).map(|data| data.to_string()), Some("29/02/2024".to_string()));
+    assert_eq!(Data::new(1900, 2, 29), None);
+    assert_eq!(Data::new(2025, 13, 1), None);
+    let inicio = Data::new(1997, 10, 7).unwrap();
+    assert_eq!(inicio.somar_dias(1000), Data::new(2000, 7, 3).unwrap());
+    assert_eq!(inicio.somar_dias(9999), Data::new(2025, 2, 21).unwrap());
+    assert_eq!(inicio.dias_ate(&Data::new(2025, 2, 22).unwrap()), 10000);
+    assert_eq!(Data::new(2025, 1, 1).unwrap().somar_dias(-1), Data::new(2024, 12, 31).unwrap());
+}
@@ -0,0 +1,181 @@
+use validador_br::br_code::{crc16, VALOR_MAXIMO};
+use validador_br::erro::ErroValidacao;
+use validador_br::types::{BrCode, ChavePix};
+use validador_br::validador::Validador;
+
+fn tlv(id: &str, valor: &str) -> String {
+    format!("{id}{:02}{valor}", valor.len())
+}
+
+/// Completa o payload com o campo 63 e o CRC16
+fn com_crc(sem_crc: &str) -> String {
+    let dados = format!("{sem_crc}6304");
+    format!("{dados}{:04X}", crc16(dados.as_bytes()))
+}
+
+/// Payload com os campos obrigatórios, substituindo os informados
+fn payload(substituir: &[(&str, &str)]) -> String {
+    let conta = tlv("00", "br.gov.bcb.pix") + &tlv("01", "a@exemplo.com");
+    let mut campos = vec![
+        ("00", "01".to_string()),
+        ("26", conta),
+        ("52", "0000".to_string()),
+        ("53", "986".to_string()),
+        ("58", "BR".to_string()),
+        ("59", "Ana".to_string()),
+        ("60", "Rio".to_string()),
+    ];
+    for (id, valor) in substituir {
+        match campos.iter_mut().find(|(campo, _)| campo == id) {
+            Some(campo) => campo.1 = valor.to_string(),
+            None => campos.push((id, valor.to_string())),
+        }
+    }
+    com_crc(&campos.iter().filter(|(_, valor)| !valor.is_empty()).map(|(id, valor)| tlv(id, valor)).collect::<String>())
+}
+
+fn campo_invalido(payload: &str) -> &'static str {
+    match BrCode::try_from(payload) {
+        Err(ErroValidacao::CampoInvalido { campo, .. }) => campo,
+        outro => panic!("{outro:?}"),
+    }
+}
+
+#[test]
+fn test_crc16() {
+    // exemplo do manual do BR Code
+    let dados = "00020126580014br.gov.bcb.pix0136123e4567-e12b-12d1-a456-4266554400005204000053039865802BR5913Fulano de Tal6008BRASILIA62070503***6304";
+    assert_eq!(crc16(dados.as_bytes()), 0x1D3D);
+    assert_eq!(crc16(b""), 0xFFFF);
+}
+
+#[test]
+fn test_ler() {
+    let conta = tlv("00", "br.gov.bcb.pix") + &tlv("01", "fulano@exemplo.com.br");
+    let payload = com_crc(&format!(
+        "000201{}52040000530398654071234.505802BR5913Fulano de Tal6008BRASILIA{}",
+        tlv("26", &conta),
+        tlv("62", &tlv("05", "PEDIDO1234"))
+    ));
+    let pix = BrCode::try_from(payload.as_str()).unwrap();
+    assert_eq!(pix.chave, Some(ChavePix::try_from("fulano@exemplo.com.br").unwrap()));
+    assert_eq!(pix.valor, Some(123450));
+    assert_eq!(pix.nome, "Fulano de Tal");
+    assert_eq!(pix.cidade, "BRASILIA");
+    assert_eq!(pix.txid.as_deref(), Some("PEDIDO1234"));
+    assert!(!pix.unico);
+    assert_eq!(pix.codificar().unwrap(), payload);
+
+    // quebra de linha da cópia e CRC em minúsculas
+    let (dados, crc) = payload.split_at(payload.len() - 4);
+    assert!(BrCode::is_valid(&format!("{dados}{}\n", crc.to_lowercase())));
+
+    // valores sem centavos ou com uma casa decimal
+    assert_eq!(BrCode::try_from(self::payload(&[("54", "10")]).as_str()).unwrap().valor, Some(1000));
+    assert_eq!(BrCode::try_from(self::payload(&[("54", "10.5")]).as_str()).unwrap().valor, Some(1050));
+}
+
+#[test]
+fn test_gerar() {
+    let mut pix = BrCode::new(ChavePix::try_from("255.248.930-33").unwrap(), "José da Conceição", "São Paulo");
+    pix.valor = Some(1);
+    pix.txid = Some("ABC123".to_string());
+    pix.info_adicional = Some("Pedido número 42".to_string());
+    let payload = pix.codificar().unwrap();
+    assert!(payload.contains("01112552489303302"));
+    assert!(payload.contains("0216Pedido numero 42"));
+    assert!(payload.contains("54040.01"));
+    assert!(payload.contains("5917Jose da Conceicao6009Sao Paulo"));
+    assert!(payload.contains("62100506ABC123"));
+    assert!(BrCode::is_valid(&payload));
+    assert!(pix.validar());
+
+    let lido: BrCode = payload.parse().unwrap();
+    assert_eq!(lido.nome, "Jose da Conceicao");
+    assert_eq!(lido.valor, Some(1));
+    assert_eq!(lido.info_adicional.as_deref(), Some("Pedido numero 42"));
+    assert_eq!(lido.codificar().unwrap(), payload);
+}
+
+#[test]
+fn test_dinamico() {
+    let mut pix = BrCode::dinamico("pix.exemplo.com.br/qr/v2/9d36b84f-c70b-478f-b95c-12729b90ca25", "Loja", "Curitiba");
+    pix.unico = true;
+    let payload = pix.codificar().unwrap();
+    assert!(payload.starts_with("000201010212"));
+    let lido = BrCode::try_from(payload.as_str()).unwrap();
+    assert_eq!(lido.chave, None);
+    assert_eq!(lido.url, pix.url);
+    assert!(lido.unico);
+    assert_eq!(lido.txid, None);
+}
+
+#[test]
+fn test_br_code_invalido() {
+    let valido = payload(&[]);
+    assert!(BrCode::is_valid(&valido));
+
+    // CRC alterado
+    let (dados, crc) = valido.split_at(valido.len() - 4);
+    let alterado = format!("{dados}{}", if crc == "0000" { "0001" } else { "0000" });
+    assert!(matches!(
+        BrCode::try_from(alterado.as_str()),
+        Err(ErroValidacao::DigitoVerificador { .. })
+    ));
+    // sem o campo 63
+    assert_eq!(campo_invalido(dados), "CRC");
+
+    let outro_arranjo = tlv("00", "br.gov.bcb.xyz") + &tlv("01", "a@exemplo.com");
+    assert_eq!(campo_invalido(&payload(&[("26", &outro_arranjo)])), "GUI");
+    assert_eq!(campo_invalido(&payload(&[("26", &tlv("00", "br.gov.bcb.pix"))])), "chave");
+    let chave_invalida = tlv("00", "br.gov.bcb.pix") + &tlv("01", "a@exemplo");
+    assert_eq!(campo_invalido(&payload(&[("26", &chave_invalida)])), "e-mail");
+    assert_eq!(campo_invalido(&payload(&[("53", "840")])), "moeda");
+    assert_eq!(campo_invalido(&payload(&[("59", "")])), "nome");
+    assert_eq!(campo_invalido(&payload(&[("00", "02")])), "formato");
+    assert_eq!(campo_invalido(&payload(&[("54", "1.000")])), "valor");
+    assert_eq!(campo_invalido(&payload(&[("54", "-1.00")])), "valor");
+    // tamanho do campo maior que o restante do payload
+    assert_eq!(campo_invalido(&com_crc("000201269900")), "TLV");
+}
+
+#[test]
+fn test_br_code_limites() {
+    // o leitor aplica os mesmos limites do gerador, então todo payload válido pode ser gerado novamente
+    let nome_longo = payload(&[("59", "Um nome com mais de 25 letras")]);
+    assert_eq!(campo_invalido(&nome_longo), "nome");
+    assert!(!BrCode::is_valid(&nome_longo));
+    assert_eq!(campo_invalido(&payload(&[("60", "Cidade com mais de 15")])), "cidade");
+    assert_eq!(campo_invalido(&payload(&[("62", &tlv("05", "pedido-1"))])), "txid");
+    assert_eq!(campo_invalido(&payload(&[("54", "9999999999999")])), "valor");
+
+    for valido in [
+        payload(&[("59", "Nome com 25 letras exatas"), ("60", "Cidade 15 letra")]),
+        payload(&[("54", "9999999999.99"), ("62", &tlv("05", "ABC123"))]),
+    ] {
+        let pix = BrCode::try_from(valido.as_str()).unwrap();
+        assert!(BrCode::is_valid(&valido));
+        assert!(pix.validar());
+        assert_eq!(BrCode::try_from(pix.codificar().unwrap()), Ok(pix));
+    }
+}
+
+#[test]
+fn test_gerar_invalido() {
+    let chave = ChavePix::try_from("+5511987654321").unwrap();
+    let campo = |pix: BrCode| match pix.codificar() {
+        Err(ErroValidacao::CampoInvalido { campo, .. }) => campo,
+        outro => panic!("{outro:?}"),
+    };
+    assert_eq!(campo(BrCode::new(chave.clone(), "Um nome com mais de 25 letras", "Rio")), "nome");
+    assert_eq!(campo(BrCode::new(chave.clone(), "Ana", "")), "cidade");
+    assert_eq!(
+        campo(BrCode { txid: Some("pedido-1".to_string()), ..BrCode::new(chave.clone(), "Ana", "Rio") }),
+        "txid"
+    );
+    assert_eq!(campo(BrCode { chave: None, ..BrCode::new(chave.clone(), "Ana", "Rio") }), "chave");
+    assert_eq!(campo(BrCode { valor: Some(u64::MAX), ..BrCode::new(chave.clone(), "Ana", "Rio") }), "valor");
+    assert_eq!(campo(BrCode { valor: Some(VALOR_MAXIMO + 1), ..BrCode::new(chave.clone(), "Ana", "Rio") }), "valor");
+    let maximo = BrCode { valor: Some(VALOR_MAXIMO), ..BrCode::new(chave, "Ana", "Rio") };
+    assert!(maximo.codificar().unwrap().contains("54139999999999.99"));
+}
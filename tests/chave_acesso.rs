@@ -0,0 +1,151 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::formatar::Formatar;
+use validador_br::gerador_dv::GeradorDv;
+use validador_br::owned::{CnpjBuf, CpfBuf};
+use validador_br::types::{ChaveAcesso, Emitente, ModeloFiscal, PartesChaveAcesso, Uf};
+use validador_br::validador::Validador;
+
+const NFE: &str = "35230576553412000110550010000012341000000014";
+const NFCE_CPF: &str = "41241000025524893033650010000000011000012348";
+const NFE_ALFANUMERICO: &str = "35250712ABC34501DE35550010000012341000000019";
+
+fn partes() -> PartesChaveAcesso {
+    PartesChaveAcesso {
+        uf: Uf::SP,
+        ano: 2023,
+        mes: 5,
+        emitente: Emitente::Cnpj(CnpjBuf::try_from("76.553.412/0001-10").unwrap()),
+        modelo: ModeloFiscal::Nfe,
+        serie: 1,
+        numero: 1234,
+        tipo_emissao: 1,
+        codigo_numerico: 1,
+    }
+}
+
+#[test]
+fn test_chave_acesso() {
+    assert!(ChaveAcesso::is_valid(NFE));
+    assert!(ChaveAcesso::is_valid(NFCE_CPF));
+    assert_eq!(ChaveAcesso(NFE).partes(), Ok(partes()));
+    assert_eq!(
+        ChaveAcesso(NFE).formatar(),
+        "3523 0576 5534 1200 0110 5500 1000 0012 3410 0000 0014"
+    );
+    assert_eq!(ChaveAcesso(&ChaveAcesso(NFE).formatar()).canonico(), NFE);
+    assert_eq!(ChaveAcesso::gerar_dv(&NFE[..43]), Ok(vec![4]));
+}
+
+#[test]
+fn test_emitente_cpf() {
+    let partes = ChaveAcesso(NFCE_CPF).partes().unwrap();
+    assert_eq!(partes.uf, Uf::PR);
+    assert_eq!((partes.ano, partes.mes), (2024, 10));
+    assert_eq!(partes.modelo, ModeloFiscal::Nfce);
+    assert_eq!(partes.emitente, Emitente::Cpf(CpfBuf::try_from("255.248.930-33").unwrap()));
+    assert_eq!(partes.numero, 1);
+    assert_eq!(partes.codigo_numerico, 1234);
+    assert_eq!(partes.montar().unwrap(), NFCE_CPF);
+
+    // campos que também formam um CNPJ válido são lidos como CNPJ
+    let ambiguo = "41241000000639884962550010000000011000012341";
+    assert_eq!(
+        ChaveAcesso(ambiguo).partes().unwrap().emitente,
+        Emitente::Cnpj(CnpjBuf::try_from("00000639884962").unwrap())
+    );
+}
+
+#[test]
+fn test_montar() {
+    assert_eq!(partes().montar().unwrap(), NFE);
+
+    let nfce = PartesChaveAcesso {
+        modelo: ModeloFiscal::Nfce,
+        ..partes()
+    };
+    let chave = nfce.montar().unwrap();
+    assert_eq!(chave, "35230576553412000110650010000012341000000017");
+    assert_eq!(ChaveAcesso(&chave).partes(), Ok(nfce));
+
+    for modelo in ModeloFiscal::TODOS {
+        let chave = PartesChaveAcesso { modelo, ..partes() }.montar().unwrap();
+        assert_eq!(ChaveAcesso(&chave).partes().unwrap().modelo, modelo);
+    }
+
+    assert_eq!(
+        PartesChaveAcesso { mes: 13, ..partes() }.montar(),
+        Err(ErroValidacao::CampoInvalido { campo: "mês", encontrado: "13".to_string() })
+    );
+    assert!(PartesChaveAcesso { numero: 1_000_000_000, ..partes() }.montar().is_err());
+    assert!(PartesChaveAcesso { ano: 1999, ..partes() }.montar().is_err());
+}
+
+#[test]
+fn test_chave_acesso_invalida() {
+    assert_eq!(
+        ChaveAcesso::verificar("35230576553412000110550010000012341000000015"),
+        Err(ErroValidacao::DigitoVerificador { esperado: vec![4], encontrado: vec![5] })
+    );
+    assert_eq!(
+        ChaveAcesso::verificar(&NFE[..43]),
+        Err(ErroValidacao::QuantidadeDigitos { minimo: 44, maximo: 44, encontrado: 43 })
+    );
+
+    // campos inválidos, com o dígito verificador recalculado
+    let completar = |base: &str| format!("{base}{}", ChaveAcesso::gerar_dv(base).unwrap()[0]);
+    assert_eq!(
+        ChaveAcesso::verificar(&completar(&format!("99{}", &NFE[2..43]))),
+        Err(ErroValidacao::UfInvalida { encontrado: "99".to_string() })
+    );
+    assert_eq!(
+        ChaveAcesso::verificar(&completar(&format!("352313{}", &NFE[6..43]))),
+        Err(ErroValidacao::CampoInvalido { campo: "mês", encontrado: "13".to_string() })
+    );
+    assert_eq!(
+        ChaveAcesso::verificar(&completar(&format!("{}56{}", &NFE[..20], &NFE[22..43]))),
+        Err(ErroValidacao::CampoInvalido { campo: "modelo", encontrado: "56".to_string() })
+    );
+    // CNPJ do emitente com o dígito verificador incorreto
+    assert!(matches!(
+        ChaveAcesso::verificar(&completar(&format!("{}11{}", &NFE[..18], &NFE[20..43]))),
+        Err(ErroValidacao::DigitoVerificador { .. })
+    ));
+}
+
+#[test]
+fn test_emitente_cnpj_alfanumerico() {
+    let partes = PartesChaveAcesso {
+        ano: 2025,
+        mes: 7,
+        emitente: Emitente::Cnpj(CnpjBuf::try_from("12.ABC.345/01DE-35").unwrap()),
+        ..partes()
+    };
+    assert_eq!(partes.montar().unwrap(), NFE_ALFANUMERICO);
+    assert_eq!(ChaveAcesso::completar(&NFE_ALFANUMERICO[..43]).unwrap(), format!("{}-9", &NFE_ALFANUMERICO[..43]));
+
+    assert!(ChaveAcesso::is_valid(NFE_ALFANUMERICO));
+    assert!(ChaveAcesso::is_valid("35250712abc34501de35550010000012341000000019"));
+    assert_eq!(ChaveAcesso(NFE_ALFANUMERICO).partes(), Ok(partes));
+    assert_eq!(
+        ChaveAcesso(NFE_ALFANUMERICO).formatar(),
+        "3525 0712 ABC3 4501 DE35 5500 1000 0012 3410 0000 0019"
+    );
+
+    assert!(matches!(
+        ChaveAcesso::verificar("35250712ABC34501DE35550010000012341000000018"),
+        Err(ErroValidacao::DigitoVerificador { .. })
+    ));
+    // letras fora da raiz e da ordem do CNPJ
+    assert_eq!(
+        ChaveAcesso::verificar("35250712ABC34501DE3A550010000012341000000019"),
+        Err(ErroValidacao::CaractereInvalido { caractere: 'A', posicao: 19 })
+    );
+    assert_eq!(
+        ChaveAcesso::verificar("3525 0712 ABC3 4501 DE35 55A0 1000 0012 3410 0000 0019"),
+        Err(ErroValidacao::CaractereInvalido { caractere: 'A', posicao: 27 })
+    );
+    assert_eq!(
+        ChaveAcesso::gerar_dv("A5250712ABC34501DE3555001000001234100000001"),
+        Err(ErroValidacao::CaractereInvalido { caractere: 'A', posicao: 0 })
+    );
+}
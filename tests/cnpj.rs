@@ -0,0 +1,77 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::owned::CnpjBuf;
+use validador_br::types::Cnpj;
+use validador_br::validador::Validador;
+
+#[test]
+fn test_raiz_ordem() {
+    assert_eq!(Cnpj("76.553.412/0001-10").raiz(), Ok("76553412".to_string()));
+    assert_eq!(Cnpj("76.553.412/0001-10").ordem(), Ok("0001".to_string()));
+    assert_eq!(Cnpj("12.ABC.345/01DE-35").raiz(), Ok("12ABC345".to_string()));
+    assert_eq!(Cnpj("12.ABC.345/01DE-35").ordem(), Ok("01DE".to_string()));
+
+    let cnpj = CnpjBuf::try_from("12.ABC.345/01DE-35").unwrap();
+    assert_eq!(cnpj.raiz(), "12ABC345");
+    assert_eq!(cnpj.ordem(), "01DE");
+
+    // O CNPJ é validado antes
+    assert!(matches!(
+        Cnpj("76.553.412/0001-11").raiz(),
+        Err(ErroValidacao::DigitoVerificador { .. })
+    ));
+}
+
+#[test]
+fn test_matriz() {
+    assert!(Cnpj("76.553.412/0001-10").is_matriz());
+    assert!(!Cnpj("76.553.412/0001-11").is_matriz());
+    assert!(!Cnpj("12.ABC.345/01DE-35").is_matriz());
+    assert!(CnpjBuf::try_from("76553412000110").unwrap().is_matriz());
+}
+
+#[test]
+fn test_mesma_empresa() {
+    let matriz = CnpjBuf::try_from("76553412000110").unwrap();
+    let filial = matriz.filial("0002").unwrap();
+    assert!(matriz.mesma_empresa(&filial));
+    assert!(Cnpj("76.553.412/0001-10").mesma_empresa(&Cnpj(filial.as_str())));
+    assert!(!Cnpj("76.553.412/0001-10").mesma_empresa(&Cnpj("12.ABC.345/01DE-35")));
+    // Um CNPJ inválido não pertence a nenhuma empresa
+    assert!(!Cnpj("76.553.412/0001-10").mesma_empresa(&Cnpj("76.553.412/0002-01")));
+}
+
+#[test]
+fn test_from_raiz() {
+    let filial = CnpjBuf::from_raiz("76.553.412", "0002").unwrap();
+    assert_eq!(filial.to_string(), "76.553.412/0002-00");
+    assert!(Cnpj::is_valid(filial.as_str()));
+    assert_eq!(CnpjBuf::from_raiz("76553412", "0001").unwrap().as_str(), "76553412000110");
+    assert_eq!(CnpjBuf::from_raiz("12abc345", "01de").unwrap().as_str(), "12ABC34501DE35");
+
+    assert_eq!(
+        CnpjBuf::from_raiz("7655341", "0002"),
+        Err(ErroValidacao::QuantidadeDigitos { minimo: 8, maximo: 8, encontrado: 7 })
+    );
+    assert_eq!(
+        CnpjBuf::from_raiz("76553412", "2"),
+        Err(ErroValidacao::QuantidadeDigitos { minimo: 4, maximo: 4, encontrado: 1 })
+    );
+}
+
+#[test]
+fn test_from_raiz_ordem_zero() {
+    let erro = ErroValidacao::CampoInvalido { campo: "ordem", encontrado: "0000".to_string() };
+    assert_eq!(CnpjBuf::from_raiz("76553412", "0000"), Err(erro.clone()));
+    assert_eq!(CnpjBuf::try_from("76553412000110").unwrap().filial("0000"), Err(erro));
+}
+
+#[test]
+fn test_filiais_validas() {
+    let matriz = CnpjBuf::try_from("76553412000110").unwrap();
+    for ordem in 1..=200u16 {
+        let filial = matriz.filial(&format!("{ordem:04}")).unwrap();
+        assert!(Cnpj::is_valid(filial.as_str()), "{filial}");
+        assert_eq!(filial.is_matriz(), ordem == 1);
+        assert!(filial.mesma_empresa(&matriz));
+    }
+}
@@ -0,0 +1,120 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::formatar::Formatar;
+use validador_br::owned::CpfBuf;
+use validador_br::types::{Documento, TipoDocumento, Uf};
+use validador_br::validador::{PoliticaRepeticao, Validador};
+
+fn tipos(numero: &str) -> Vec<&'static str> {
+    Documento::detectar(numero)
+        .iter()
+        .map(|documento| documento.tipo().identificador())
+        .collect()
+}
+
+#[test]
+fn test_detectar() {
+    assert_eq!(tipos("255.248.930-33")[0], "cpf");
+    assert_eq!(tipos("76.553.412/0001-10")[0], "cnpj");
+    assert_eq!(tipos("12.ABC.345/01DE-35"), vec!["cnpj"]);
+    assert_eq!(tipos("184 1844 6218 0018")[0], "cns");
+    assert_eq!(tipos("608.37951.54-6")[0], "pis");
+    assert_eq!(tipos("SP 110.042.490.114"), vec!["ie"]);
+    assert_eq!(tipos("7891000000090"), vec!["gs1"]);
+}
+
+#[test]
+fn test_detectar_ambiguo() {
+    // Válido como CPF, CNH e código de barras, na ordem de preferência
+    assert_eq!(tipos("10000000108"), vec!["cpf", "cnh", "gs1"]);
+    // Pis e Renavam de 11 dígitos usam o mesmo cálculo
+    assert_eq!(tipos("10000000091"), vec!["pis", "cnh", "renavam"]);
+    // Um CPF com o dígito verificador incorreto ainda pode ser um Pis válido
+    assert_eq!(tipos("255.248.930-34"), vec!["pis", "renavam"]);
+}
+
+#[test]
+fn test_detectar_ordem() {
+    // Válido como CPF e Pis; a máscara informada decide o primeiro candidato
+    assert_eq!(tipos("380.502.432-06")[..2], ["cpf", "pis"]);
+    assert_eq!(tipos("380.50243.20-6")[..2], ["pis", "cpf"]);
+    assert_eq!(tipos("38050243206")[..2], ["cpf", "pis"]);
+}
+
+#[test]
+fn test_detectar_invalido() {
+    assert!(tipos("255.248.930-35").is_empty());
+    assert!(tipos("111.111.111-11").is_empty());
+    assert!(tipos("").is_empty());
+    assert!(tipos("abc").is_empty());
+}
+
+#[test]
+fn test_detectar_normaliza() {
+    let candidatos = Documento::detectar("255.248.930-33");
+    match &candidatos[0] {
+        Documento::Cpf(cpf) => assert_eq!(cpf.as_str(), "25524893033"),
+        outro => panic!("{outro:?}"),
+    }
+}
+
+#[test]
+fn test_documento_from_str() {
+    let documento: Documento = "cpf:255.248.930-33".parse().unwrap();
+    assert_eq!(documento, Documento::Cpf(CpfBuf::try_from("25524893033").unwrap()));
+    assert_eq!(documento.tipo(), TipoDocumento::Cpf);
+    assert_eq!(documento.as_str(), "25524893033");
+    assert!(documento.validar());
+
+    let documento: Documento = "CNPJ:76553412000110".parse().unwrap();
+    assert_eq!(documento.tipo(), TipoDocumento::Cnpj);
+    let documento: Documento = "ie:SP 110.042.490.114".parse().unwrap();
+    match &documento {
+        Documento::Ie(ie) => assert_eq!(ie.uf(), Uf::SP),
+        outro => panic!("{outro:?}"),
+    }
+
+    assert_eq!(
+        "cpf:255.248.930-34".parse::<Documento>(),
+        Err(ErroValidacao::DigitoVerificador {
+            esperado: vec![3, 3],
+            encontrado: vec![3, 4],
+        })
+    );
+    assert_eq!(
+        "xyz:25524893033".parse::<Documento>(),
+        Err(ErroValidacao::TipoInvalido { encontrado: "xyz".to_string() })
+    );
+    assert!(matches!("25524893033".parse::<Documento>(), Err(ErroValidacao::TipoInvalido { .. })));
+}
+
+#[test]
+fn test_documento_display() {
+    for texto in ["cpf:255.248.930-33", "cnpj:76.553.412/0001-10", "pis:608.37951.54-6", "ie:SP 110.042.490.114"] {
+        let documento: Documento = texto.parse().unwrap();
+        assert_eq!(documento.to_string(), texto);
+        assert_eq!(documento.to_string().parse::<Documento>().unwrap(), documento);
+    }
+
+    let documento: Documento = "cnpj:76553412000110".parse().unwrap();
+    assert_eq!(documento.formatar(), "76.553.412/0001-10");
+    assert_eq!(documento.canonico(), "76553412000110");
+}
+
+#[test]
+fn test_documento_validador() {
+    assert!(Documento::is_valid("cpf:255.248.930-33"));
+    assert!(Documento::is_valid("titulo:0043 5687 0906"));
+    assert!(!Documento::is_valid("cnpj:255.248.930-33"));
+    assert_eq!(Documento::verificar("cpf:111.111.111-11"), Err(ErroValidacao::DigitosRepetidos));
+    assert!(Documento::verificar_com("cpf:111.111.111-11", PoliticaRepeticao::Permitir).is_ok());
+}
+
+#[test]
+fn test_tipo_documento() {
+    for tipo in TipoDocumento::TODOS {
+        assert_eq!(tipo.identificador().parse::<TipoDocumento>(), Ok(tipo));
+        assert_eq!(tipo.to_string(), tipo.identificador());
+    }
+    assert_eq!("Pasep".parse::<TipoDocumento>(), Ok(TipoDocumento::Pis));
+    assert_eq!(TipoDocumento::Cnpj.nome(), "Cadastro Nacional da Pessoa Jurídica");
+}
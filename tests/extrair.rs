@@ -0,0 +1,97 @@
+use validador_br::extrair::{extrair, Extrator, Ocorrencia};
+use validador_br::types::TipoDocumento;
+
+fn encontrados(texto: &str) -> Vec<(TipoDocumento, &str, bool)> {
+    extrair(texto)
+        .map(|Ocorrencia { tipo, texto, valido, .. }| (tipo, texto, valido))
+        .collect()
+}
+
+#[test]
+fn test_extrair_formatados() {
+    let texto = "Prezados, meu CPF é 255.248.930-33 e o CNPJ da loja 76.553.412/0001-10. \
+                 Paguei com o cartão 5312 8338 4531 6765.";
+    assert_eq!(
+        encontrados(texto),
+        vec![
+            (TipoDocumento::Cpf, "255.248.930-33", true),
+            (TipoDocumento::Cnpj, "76.553.412/0001-10", true),
+            (TipoDocumento::CartaoCredito, "5312 8338 4531 6765", true),
+        ]
+    );
+
+    // Números formatados são retornados mesmo com o dígito verificador incorreto
+    assert_eq!(encontrados("CPF: 255.248.930-34"), vec![(TipoDocumento::Cpf, "255.248.930-34", false)]);
+    assert_eq!(encontrados("12.ABC.345/01DE-35"), vec![(TipoDocumento::Cnpj, "12.ABC.345/01DE-35", true)]);
+}
+
+#[test]
+fn test_extrair_sem_formatacao() {
+    let texto = "cpf 25524893033, cnpj 76553412000110, renavam 639884962 e cartão 5312833845316765";
+    let tipos = [TipoDocumento::Cpf, TipoDocumento::Cnpj, TipoDocumento::Renavam, TipoDocumento::CartaoCredito];
+    let ocorrencias: Vec<Ocorrencia> = Extrator::new(texto).tipos(&tipos).collect();
+    let resumo: Vec<(TipoDocumento, &str)> = ocorrencias.iter().map(|o| (o.tipo, o.texto)).collect();
+    assert_eq!(
+        resumo,
+        vec![
+            (TipoDocumento::Cpf, "25524893033"),
+            (TipoDocumento::Cnpj, "76553412000110"),
+            (TipoDocumento::Renavam, "639884962"),
+            (TipoDocumento::CartaoCredito, "5312833845316765"),
+        ]
+    );
+    for ocorrencia in &ocorrencias {
+        assert_eq!(&texto[ocorrencia.inicio..ocorrencia.fim], ocorrencia.texto);
+        assert!(ocorrencia.valido);
+        assert!(ocorrencia.documento().is_some());
+    }
+
+    // Sem formatação, números inválidos são ignorados
+    assert!(encontrados("pedido 25524893035").is_empty());
+}
+
+#[test]
+fn test_extrair_falsos_positivos() {
+    let textos = [
+        "Ligue para (11) 98765-4321 ou +55 11 98765-4321",
+        "tel 11987654321",
+        "celular: 21999998888",
+        "Vencimento em 12/05/2023, pago em 2023-05-12 às 15:30",
+        "Protocolo 202305121530",
+        "Nota fiscal 000.123.456",
+        "Código ABC25524893033",
+        "Número 255.248.930-33-7",
+        "Repetidos 111.111.111-11 não são ignorados se formatados, mas 11111111111 sim",
+    ];
+    for texto in &textos[..8] {
+        assert!(encontrados(texto).is_empty(), "{texto}: {:?}", encontrados(texto));
+    }
+    assert_eq!(encontrados(textos[8]), vec![(TipoDocumento::Cpf, "111.111.111-11", false)]);
+}
+
+#[test]
+fn test_extrair_numeros_aleatorios() {
+    // Gerador congruencial linear, para que o teste seja reprodutível
+    let mut semente: u64 = 42;
+    let mut proximo = move |tamanho: u32| {
+        semente = semente.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let menor = 10u64.pow(tamanho - 1);
+        menor + (semente >> 11) % (9 * menor)
+    };
+    for _ in 0..1000 {
+        let curtos = format!("pedido {} e protocolo {}", proximo(9), proximo(10));
+        assert!(encontrados(&curtos).is_empty(), "{curtos}: {:?}", encontrados(&curtos));
+
+        let longo = format!("pedido {}", proximo(11));
+        for (tipo, texto, _) in encontrados(&longo) {
+            assert!(![TipoDocumento::Renavam, TipoDocumento::Cnh].contains(&tipo), "{texto}");
+        }
+    }
+}
+
+#[test]
+fn test_extrair_unicode() {
+    let texto = "Ação nº 255.248.930-33 — obrigado";
+    let ocorrencia = extrair(texto).next().unwrap();
+    assert_eq!(&texto[ocorrencia.inicio..ocorrencia.fim], "255.248.930-33");
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "gerador")]
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use validador_br::erro::ErroValidacao;
+use validador_br::gerador::Gerador;
+use validador_br::types::{CartaoCredito, Cnh, Cnpj, Cns, CodigoBarrasGs1, Cpf, Pis, RegiaoFiscal,
+    Renavam, Rg, TituloEleitor, Uf,
+};
+use validador_br::validador::Validador;
+use validador_br::validador_ie::verificar_ie;
+
+#[test]
+fn test_gerador_valido() {
+    let mut gerador = Gerador::new(StdRng::seed_from_u64(2023));
+    for _ in 0..200 {
+        assert!(Cpf::is_valid(&gerador.cpf()));
+        assert!(Cnpj::is_valid(&gerador.cnpj()));
+        assert!(Cnpj::is_valid(&gerador.cnpj_alfanumerico()));
+        assert!(Pis::is_valid(&gerador.pis()));
+        assert!(Cns::is_valid(&gerador.cns()));
+        assert!(Cnh::is_valid(&gerador.cnh()));
+        assert!(Renavam::is_valid(&gerador.renavam()));
+        assert!(TituloEleitor::is_valid(&gerador.titulo_eleitor()));
+        assert!(Rg::is_valid(&gerador.rg()));
+        assert!(CartaoCredito::is_valid(&gerador.cartao_credito()));
+        assert!(CodigoBarrasGs1::is_valid(&gerador.codigo_barras_gs1()));
+    }
+}
+
+#[test]
+fn test_gerador_ie() {
+    let mut gerador = Gerador::new(StdRng::seed_from_u64(7));
+    for uf in Uf::TODAS {
+        for _ in 0..20 {
+            let ie = gerador.ie(uf);
+            assert_eq!(verificar_ie(uf, &ie), Ok(()), "{uf} {ie}");
+        }
+    }
+
+    let mut gerador = Gerador::new(StdRng::seed_from_u64(7)).formatado(true);
+    let ie = gerador.ie(Uf::SP);
+    assert_eq!(ie.len(), 15);
+    assert!(verificar_ie(Uf::SP, &ie).is_ok());
+}
+
+#[test]
+fn test_gerador_reproduzivel() {
+    let mut a = Gerador::new(StdRng::seed_from_u64(42));
+    let mut b = Gerador::new(StdRng::seed_from_u64(42));
+    for _ in 0..10 {
+        assert_eq!(a.cpf(), b.cpf());
+        assert_eq!(a.cnpj(), b.cnpj());
+    }
+}
+
+#[test]
+fn test_gerador_opcoes() {
+    let mut gerador = Gerador::new(StdRng::seed_from_u64(1)).formatado(true);
+    let cpf = gerador.cpf();
+    assert_eq!(cpf.len(), 14);
+    assert_eq!(&cpf[3..4], ".");
+    assert_eq!(&cpf[11..12], "-");
+    assert!(Cpf::is_valid(&cpf));
+
+    let cnpj = gerador.cnpj();
+    assert_eq!(&cnpj[10..16], "/0001-");
+    assert!(Cnpj::is_valid(&cnpj));
+
+    let alfanumerico = gerador.cnpj_alfanumerico();
+    assert_eq!(alfanumerico.len(), 18);
+    assert!(Cnpj::is_valid(&alfanumerico));
+
+    let filial = gerador.cnpj_filial(12).unwrap();
+    assert_eq!(&filial[10..16], "/0012-");
+    assert!(Cnpj::is_valid(&filial));
+    assert_eq!(&gerador.cnpj_filial(9999).unwrap()[10..16], "/9999-");
+
+    let cpf = gerador.cpf_regiao(RegiaoFiscal::Oitava);
+    assert_eq!(&cpf[10..11], "8");
+    assert!(Cpf::is_valid(&cpf));
+
+    let mut gerador = Gerador::new(StdRng::seed_from_u64(1));
+    for regiao in RegiaoFiscal::TODAS {
+        let cpf = gerador.cpf_regiao(regiao);
+        assert_eq!(cpf.len(), 11);
+        assert_eq!(Cpf(&cpf).regiao_fiscal(), Ok(regiao));
+    }
+}
+
+#[test]
+fn test_gerador_cnpj_ordem_invalida() {
+    let mut gerador = Gerador::new(StdRng::seed_from_u64(42));
+    for ordem in [0, 10000, 10001] {
+        assert_eq!(
+            gerador.cnpj_filial(ordem),
+            Err(ErroValidacao::CampoInvalido { campo: "ordem", encontrado: ordem.to_string() })
+        );
+    }
+}
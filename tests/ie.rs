@@ -0,0 +1,164 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::types::{Ie, Uf};
+use validador_br::validador::Validador;
+use validador_br::validador_ie::{calcular_dv_ie, verificar_ie, IeUf};
+
+/// Exemplos do roteiro de crítica do Sintegra para cada UF
+const INSCRICOES: [(Uf, &str); 33] = [
+    (Uf::AC, "01.004.823/001-12"),
+    (Uf::AL, "24000004-8"),
+    (Uf::AP, "03.012.345-9"),
+    (Uf::AM, "99.999.999-0"),
+    (Uf::BA, "123456-63"),
+    (Uf::BA, "1000003-06"),
+    (Uf::CE, "06000001-5"),
+    (Uf::DF, "07.300001.001-09"),
+    (Uf::ES, "99999999-0"),
+    (Uf::GO, "10.987.654-7"),
+    (Uf::MA, "12000038-5"),
+    (Uf::MT, "0013000001-9"),
+    (Uf::MS, "28.312.345-1"),
+    (Uf::MG, "062.307.904/0081"),
+    (Uf::PA, "15-999999-5"),
+    (Uf::PB, "06000001-5"),
+    (Uf::PR, "123.45678-50"),
+    (Uf::PE, "0321418-40"),
+    (Uf::PE, "18.1.001.0000004-9"),
+    (Uf::PI, "19.301.656-7"),
+    (Uf::RJ, "99.999.99-3"),
+    (Uf::RN, "20.040.040-1"),
+    (Uf::RN, "20.0.040.040-0"),
+    (Uf::RS, "224/3658792"),
+    (Uf::RO, "0000000062521-3"),
+    (Uf::RO, "101.62521-3"),
+    (Uf::RR, "24006628-1"),
+    (Uf::SC, "251.040.852"),
+    (Uf::SP, "110.042.490.114"),
+    (Uf::SP, "P-01100424.3/002"),
+    (Uf::SE, "27.123.456-3"),
+    (Uf::TO, "29.01.022.783-6"),
+    (Uf::TO, "29.022.783-6"),
+];
+
+#[test]
+fn test_valid_ie() {
+    for (uf, numero) in INSCRICOES {
+        assert_eq!(verificar_ie(uf, numero), Ok(()), "{uf:?} {numero}");
+        assert!(IeUf(uf, numero).validar(), "{uf:?} {numero}");
+    }
+}
+
+#[test]
+fn test_invalid_ie() {
+    for (uf, numero) in INSCRICOES.into_iter().filter(|(_, n)| !n.starts_with('P')) {
+        // altera o último dígito verificador
+        let mut alterado = numero.to_string();
+        let ultimo = alterado.pop().unwrap().to_digit(10).unwrap();
+        alterado.push(char::from_digit((ultimo + 1) % 10, 10).unwrap());
+        assert!(
+            matches!(
+                verificar_ie(uf, &alterado),
+                Err(ErroValidacao::DigitoVerificador { .. })
+            ),
+            "{uf:?} {alterado}"
+        );
+    }
+
+    assert!(!IeUf(Uf::SP, "P-01100424.4/002").validar());
+
+    // As inscrições de cada estado não são válidas em outro
+    assert!(!IeUf(Uf::SP, "062.307.904/0081").validar());
+    assert!(!IeUf(Uf::MG, "110.042.490.114").validar());
+    assert!(verificar_ie(Uf::SP, "110.042.490").is_err());
+}
+
+#[test]
+fn test_ie_prefixo() {
+    assert_eq!(
+        verificar_ie(Uf::AC, "02.004.823/001-12"),
+        Err(ErroValidacao::PrefixoInvalido {
+            encontrado: "02".to_string()
+        })
+    );
+    assert!(matches!(
+        verificar_ie(Uf::PA, "16-999999-5"),
+        Err(ErroValidacao::PrefixoInvalido { .. })
+    ));
+    assert!(matches!(
+        verificar_ie(Uf::TO, "29.04.022.783-6"),
+        Err(ErroValidacao::PrefixoInvalido { .. })
+    ));
+}
+
+#[test]
+fn test_calcular_dv_ie() {
+    // a base é o número sem os dígitos verificadores, que são retornados na ordem em que aparecem
+    assert_eq!(calcular_dv_ie(Uf::AC, "01.004.823/001"), Ok(vec![1, 2]));
+    assert_eq!(calcular_dv_ie(Uf::BA, "123456"), Ok(vec![6, 3]));
+    assert_eq!(calcular_dv_ie(Uf::MG, "062.307.904/00"), Ok(vec![8, 1]));
+    assert_eq!(calcular_dv_ie(Uf::MT, "0013000001"), Ok(vec![9]));
+    assert_eq!(calcular_dv_ie(Uf::MT, "13000001"), Ok(vec![9]));
+    assert_eq!(calcular_dv_ie(Uf::PE, "0321418"), Ok(vec![4, 0]));
+    assert_eq!(calcular_dv_ie(Uf::PE, "18.1.001.0000004"), Ok(vec![9]));
+    assert_eq!(calcular_dv_ie(Uf::RO, "101.62521"), Ok(vec![3]));
+    assert_eq!(calcular_dv_ie(Uf::TO, "29.01.022.783"), Ok(vec![6]));
+    // em SP os dígitos ficam na 9ª e na 12ª posições de 110.042.490.114
+    assert_eq!(calcular_dv_ie(Uf::SP, "11004249 11"), Ok(vec![0, 4]));
+
+    assert_eq!(
+        calcular_dv_ie(Uf::PE, "03214180"),
+        Err(ErroValidacao::QuantidadeDigitos { minimo: 7, maximo: 13, encontrado: 8 })
+    );
+    assert_eq!(
+        calcular_dv_ie(Uf::AC, "02.004.823/001"),
+        Err(ErroValidacao::PrefixoInvalido { encontrado: "02".to_string() })
+    );
+}
+
+#[test]
+fn test_ie_isento() {
+    assert!(verificar_ie(Uf::SP, "ISENTO").is_ok());
+    assert!(IeUf(Uf::RJ, " isento ").validar());
+    assert!(Ie::is_valid("BA ISENTO"));
+}
+
+#[test]
+fn test_parse_ie() {
+    assert!(Ie::is_valid("SP 110.042.490.114"));
+    assert!(Ie::is_valid("mg062.307.904/0081"));
+    assert!(Ie::try_from("SP P-01100424.3/002").is_ok());
+    assert!(IeUf::try_from((Uf::PR, "123.45678-50")).is_ok());
+    assert_eq!(
+        IeUf::try_from("XX 110.042.490.114").err(),
+        Some(ErroValidacao::UfInvalida {
+            encontrado: "XX".to_string()
+        })
+    );
+
+    let ie = Ie::try_from("RS 224/3658792").ok().unwrap();
+    assert!(ie.validar());
+    assert_eq!(ie.0 .0, Uf::RS);
+}
+
+#[test]
+fn test_formatar_ie() {
+    use validador_br::formatar::Formatar;
+
+    for (uf, numero) in INSCRICOES {
+        let ie = IeUf(uf, numero);
+        let canonico = ie.canonico();
+        assert!(verificar_ie(uf, &canonico).is_ok(), "{uf} {canonico}");
+        assert!(verificar_ie(uf, &ie.formatar()).is_ok(), "{uf} {}", ie.formatar());
+    }
+
+    assert_eq!(IeUf(Uf::SP, "110042490114").formatar(), "110.042.490.114");
+    assert_eq!(IeUf(Uf::SP, "P011004243002").formatar(), "P-01100424.3/002");
+    assert_eq!(IeUf(Uf::SP, "P-01100424.3/002").canonico(), "P011004243002");
+    assert_eq!(IeUf(Uf::MG, "0623079040081").formatar(), "062.307.904/0081");
+    assert_eq!(IeUf(Uf::AC, "0100482300112").formatar(), "01.004.823/001-12");
+    assert_eq!(IeUf(Uf::RS, "2243658792").formatar(), "224/3658792");
+    assert_eq!(IeUf(Uf::BA, "12345663").formatar(), "123456-63");
+    assert_eq!(IeUf(Uf::PE, "18100100000049").formatar(), "18.1.001.0000004-9");
+    assert_eq!(IeUf(Uf::RJ, "isento").formatar(), "ISENTO");
+    assert_eq!(Ie(IeUf(Uf::PR, "1234567850")).formatar(), "12345678-50");
+}
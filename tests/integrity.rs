@@ -1,9 +1,15 @@
+// Os testes originais constroem os tipos com `Tipo(&"...")`
+#![allow(clippy::needless_borrow)]
+
+use validador_br::erro::ErroValidacao;
 use validador_br::funcoes::mod_11;
 use validador_br::funcoes::{calc_digito, completa_esquerda, somente_digitos};
+use validador_br::formatar::Formatar;
+use validador_br::gerador_dv::GeradorDv;
 use validador_br::types::{CartaoCredito, Cnh, Cnpj, Cpf, Cns, Pis, Renavam, Rg, 
     TituloEleitor, CodigoBarrasGs1,
 };
-use validador_br::validador::Validador;
+use validador_br::validador::{PoliticaRepeticao, Validador};
 
 #[test]
 fn test_mod_11() {
@@ -371,7 +377,7 @@ fn test_parse_cpf() {
         assert!(cpf.validar());
     };
 
-    let cpf: Result<Cpf, &'static str> = "085.668.830-47".try_into();
+    let cpf: Result<Cpf, ErroValidacao> = "085.668.830-47".try_into();
     match cpf {
         Ok(_cpf) => (),
         Err(_cpf_error) => unreachable!()
@@ -383,3 +389,210 @@ fn test_parse_cpf() {
         Err(_cpf_error) => unreachable!()
     };
 }
+
+#[test]
+fn test_erro_validacao() {
+    assert_eq!(
+        Cpf::try_from("085.668.830-48").err(),
+        Some(ErroValidacao::DigitoVerificador {
+            esperado: vec![4, 7],
+            encontrado: vec![4, 8]
+        })
+    );
+    assert_eq!(
+        Cpf::verificar("085.668.83O-47"),
+        Err(ErroValidacao::CaractereInvalido {
+            caractere: 'O',
+            posicao: 10
+        })
+    );
+    assert_eq!(
+        Cnpj::try_from("76.553.412/0001-11").err(),
+        Some(ErroValidacao::DigitoVerificador {
+            esperado: vec![1, 0],
+            encontrado: vec![1, 1]
+        })
+    );
+    assert!(Pis::try_from("608.37951.54-7").is_err());
+    // rótulos não são ignorados
+    assert_eq!(
+        Cpf::verificar("CPF: 255.248.930-33"),
+        Err(ErroValidacao::CaractereInvalido {
+            caractere: 'C',
+            posicao: 0
+        })
+    );
+
+    let erro = Cpf::try_from("085.668.830-48").err().unwrap();
+    assert_eq!(
+        erro.to_string(),
+        "Dígito verificador inválido: esperado 47, encontrado 48"
+    );
+}
+
+#[test]
+fn test_quantidade_digitos() {
+    assert!(!Cpf::is_valid("123"));
+    assert!(!Cpf::is_valid(""));
+    assert!(!Cpf::is_valid("085.668.830-471"));
+    assert!(!Cnpj::is_valid("76.553.412/0001"));
+    assert!(!TituloEleitor::is_valid("00435687 09"));
+    assert!(!Cnh::is_valid("8181475674"));
+    assert!(!Rg::is_valid("14.176.381"));
+    assert!(!Pis::is_valid("608.37951.54"));
+    assert!(!Cns::is_valid("18418446218001"));
+    assert!(!Renavam::is_valid("12345678"));
+    assert!(!CartaoCredito::is_valid("5312 8338"));
+    assert!(!CodigoBarrasGs1::is_valid("789123"));
+
+    assert_eq!(
+        Cpf::verificar("123"),
+        Err(ErroValidacao::QuantidadeDigitos {
+            minimo: 11,
+            maximo: 11,
+            encontrado: 3
+        })
+    );
+    assert_eq!(
+        Renavam::verificar("1234567"),
+        Err(ErroValidacao::QuantidadeDigitos {
+            minimo: 9,
+            maximo: 11,
+            encontrado: 7
+        })
+    );
+}
+
+#[test]
+fn test_digitos_repetidos() {
+    for digito in 0..=9 {
+        let cpf = digito.to_string().repeat(11);
+        assert_eq!(Cpf::verificar(&cpf), Err(ErroValidacao::DigitosRepetidos));
+    }
+    assert_eq!(Cpf::verificar("000.000.000-00"), Err(ErroValidacao::DigitosRepetidos));
+    assert_eq!(Cnpj::verificar("11.111.111/1111-11"), Err(ErroValidacao::DigitosRepetidos));
+    assert_eq!(Pis::verificar("000.00000.00-0"), Err(ErroValidacao::DigitosRepetidos));
+    assert_eq!(Cnh::verificar("00000000000"), Err(ErroValidacao::DigitosRepetidos));
+    assert_eq!(TituloEleitor::verificar("000000000000"), Err(ErroValidacao::DigitosRepetidos));
+    assert_eq!(Renavam::verificar("00000000000"), Err(ErroValidacao::DigitosRepetidos));
+    assert_eq!(Cns::verificar("777777777777777"), Err(ErroValidacao::DigitosRepetidos));
+    assert!(Cpf::try_from("111.111.111-11").is_err());
+
+    // Sistemas legados podem armazenar sequências repetidas como valores provisórios
+    assert!(Cpf::verificar_com("111.111.111-11", PoliticaRepeticao::Permitir).is_ok());
+    assert!(Cpf::verificar_com("000.000.000-00", PoliticaRepeticao::Permitir).is_ok());
+    assert!(Cnpj::verificar_com("00.000.000/0000-00", PoliticaRepeticao::Permitir).is_ok());
+    assert!(Cpf::verificar_com("085.668.830-47", PoliticaRepeticao::Rejeitar).is_ok());
+}
+
+#[test]
+fn test_cnpj_alfanumerico() {
+    // Exemplo publicado pela Receita Federal
+    assert!(Cnpj("12.ABC.345/01DE-35").validar());
+    assert!(Cnpj::is_valid("12ABC34501DE35"));
+    assert!(Cnpj::is_valid("12.abc.345/01de-35"));
+    assert_eq!(Cnpj::gerar_dv("12.ABC.345/01DE"), Ok(vec![3, 5]));
+    assert_eq!(Cnpj::completar("12abc34501de"), Ok("12ABC34501DE-35".to_string()));
+    assert_eq!(Cnpj("12.abc.345/01de-35").canonico(), "12ABC34501DE35");
+    assert_eq!(Cnpj("12ABC34501DE35").formatar(), "12.ABC.345/01DE-35");
+
+    assert_eq!(
+        Cnpj::verificar("12.ABC.345/01DE-36"),
+        Err(ErroValidacao::DigitoVerificador {
+            esperado: vec![3, 5],
+            encontrado: vec![3, 6],
+        })
+    );
+    // Os dígitos verificadores são sempre numéricos
+    assert_eq!(
+        Cnpj::verificar("12.ABC.345/01DE-3A"),
+        Err(ErroValidacao::CaractereInvalido { caractere: 'A', posicao: 17 })
+    );
+    assert_eq!(
+        Cnpj::verificar("12.ABC.345/01DE-3_"),
+        Err(ErroValidacao::CaractereInvalido { caractere: '_', posicao: 17 })
+    );
+    assert!(Cnpj::verificar("12.ABC.345/01D-35").is_err());
+}
+
+#[test]
+fn test_gerar_dv() {
+    assert_eq!(Cpf::gerar_dv("123456789"), Ok(vec![0, 9]));
+    assert_eq!(Cpf::completar("123.456.789"), Ok("123456789-09".to_string()));
+    assert_eq!(Cpf::completar("085.668.830"), Ok("085668830-47".to_string()));
+    assert_eq!(Cnpj::completar("76.553.412/0001"), Ok("765534120001-10".to_string()));
+    assert_eq!(Pis::completar("608.37951.54"), Ok("6083795154-6".to_string()));
+    assert_eq!(Renavam::completar("0269417017"), Ok("0269417017-4".to_string()));
+    assert_eq!(Renavam::completar("89135336"), Ok("89135336-4".to_string()));
+    assert_eq!(Cnh::completar("818147567"), Ok("818147567-44".to_string()));
+    assert_eq!(TituloEleitor::completar("0043568709"), Ok("0043568709-06".to_string()));
+    assert_eq!(Rg::completar("14.176.381"), Ok("14176381-4".to_string()));
+    assert_eq!(Cns::completar("18418446218"), Ok("18418446218-0018".to_string()));
+    assert_eq!(Cns::completar("96218346145000"), Ok("96218346145000-2".to_string()));
+    assert_eq!(CodigoBarrasGs1::completar("789030058465"), Ok("789030058465-1".to_string()));
+    assert_eq!(CartaoCredito::completar("5312 8338 4531 676"), Ok("531283384531676-5".to_string()));
+
+    assert!(Cpf::gerar_dv("12345678").is_err());
+    assert!(Cns::gerar_dv("38418446218").is_err());
+}
+
+#[test]
+fn test_calcular_dv_base_curta() {
+    let erro = ErroValidacao::QuantidadeDigitos {
+        minimo: 9,
+        maximo: 9,
+        encontrado: 3,
+    };
+    assert_eq!(Cpf::calcular_dv(&[1, 2, 3]), Err(erro));
+    assert!(Cnpj::calcular_dv(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1]).is_err());
+    assert!(TituloEleitor::calcular_dv(&[0, 0, 4]).is_err());
+    assert!(Cns::calcular_dv(&[]).is_err());
+}
+
+#[test]
+fn test_completar_valida() {
+    type Completar = fn(&str) -> Result<String, ErroValidacao>;
+    type IsValid = fn(&str) -> bool;
+    let documentos: Vec<(&str, Completar, IsValid)> = vec![
+        ("974749266", Cpf::completar, Cpf::is_valid),
+        ("455184338000", Cnpj::completar, Cnpj::is_valid),
+        ("3177318080", Pis::completar, Pis::is_valid),
+        ("5587939703", Renavam::completar, Renavam::is_valid),
+        ("288513043", Cnh::completar, Cnh::is_valid),
+        ("8645582519", TituloEleitor::completar, TituloEleitor::is_valid),
+        ("28530378", Rg::completar, Rg::is_valid),
+        ("735005385003", CodigoBarrasGs1::completar, CodigoBarrasGs1::is_valid),
+        ("544049704224467", CartaoCredito::completar, CartaoCredito::is_valid),
+        ("14038713997", Cns::completar, Cns::is_valid),
+    ];
+
+    for (base, completar, is_valid) in documentos {
+        let completo = completar(base).unwrap();
+        assert!(is_valid(&completo), "{completo}");
+    }
+
+    // Códigos de barras cuja soma é múltiplo de 10 têm dígito verificador 0
+    assert_eq!(CodigoBarrasGs1::gerar_dv("789100000009"), Ok(vec![0]));
+    assert!(CodigoBarrasGs1::is_valid("7891000000090"));
+}
+
+#[test]
+fn test_formatar() {
+    assert_eq!(Cpf("25524893033").formatar(), "255.248.930-33");
+    assert_eq!(Cpf("255.248.930-33").formatar(), "255.248.930-33");
+    assert_eq!(Cpf("255.248.930-33").canonico(), "25524893033");
+    assert_eq!(Cnpj("76553412000110").formatar(), "76.553.412/0001-10");
+    assert_eq!(Cnpj("14.572.457.0001-85").formatar(), "14.572.457/0001-85");
+    assert_eq!(Pis("60837951546").formatar(), "608.37951.54-6");
+    assert_eq!(Cns("184184462180018").formatar(), "184 1844 6218 0018");
+    assert_eq!(TituloEleitor("004356870906").formatar(), "0043 5687 0906");
+    assert_eq!(CartaoCredito("5312833845316765").formatar(), "5312 8338 4531 6765");
+    assert_eq!(CartaoCredito("3479 467653 71543").formatar(), "3479 4676 5371 543");
+    assert_eq!(Rg("141763814").formatar(), "14.176.381-4");
+    assert_eq!(Cnh("818.147.567-44").formatar(), "81814756744");
+    assert_eq!(Renavam("891353364").canonico(), "00891353364");
+    assert_eq!(CodigoBarrasGs1("789030058465-1").formatar(), "7890300584651");
+
+    // Números incompletos não recebem máscara
+    assert_eq!(Cpf("255.248.930").formatar(), "255248930");
+}
@@ -0,0 +1,54 @@
+use validador_br::mascarar::{mascarar_texto, redigir, Mascarar, PadraoMascara, Redator};
+use validador_br::owned::CpfBuf;
+use validador_br::types::{CartaoCredito, Cnpj, Cns, Cpf, Documento, Ie, Pis, Renavam, TipoDocumento,
+    Uf,
+};
+use validador_br::validador_ie::IeUf;
+
+#[test]
+fn test_mascarar() {
+    assert_eq!(Cpf("255.248.930-33").mascarar(), "***.248.930-**");
+    assert_eq!(Cpf("25524893033").mascarar(), "***.248.930-**");
+    assert_eq!(Cnpj("76553412000110").mascarar(), "**.553.412/0001-**");
+    assert_eq!(Pis("60837951546").mascarar(), "***.37951.54-*");
+    assert_eq!(Cns("184184462180018").mascarar(), "*** 1844 6218 ****");
+    assert_eq!(CartaoCredito("5312833845316765").mascarar(), "**** **** **** 6765");
+    assert_eq!(Renavam("639884962").mascarar(), "*******4962");
+    assert_eq!(Ie(IeUf(Uf::SP, "110042490114")).mascarar(), "***.***.**0.114");
+
+    let cpf = CpfBuf::try_from("255.248.930-33").unwrap();
+    assert_eq!(cpf.mascarar(), "***.248.930-**");
+    let documento: Documento = "cpf:25524893033".parse().unwrap();
+    assert_eq!(documento.mascarar(), "***.248.930-**");
+}
+
+#[test]
+fn test_mascarar_com() {
+    let cpf = Cpf("255.248.930-33");
+    assert_eq!(cpf.mascarar_com(&PadraoMascara::Ultimos(2)), "***.***.***-33");
+    assert_eq!(cpf.mascarar_com(&PadraoMascara::Total), "***.***.***-**");
+    assert_eq!(cpf.mascarar_com(&PadraoMascara::Mascara("###.***.***-##".to_string())), "255.***.***-33");
+    // Máscaras incompatíveis ocultam todos os dígitos
+    assert_eq!(cpf.mascarar_com(&PadraoMascara::Mascara("###-##".to_string())), "***.***.***-**");
+    assert_eq!(mascarar_texto("12.ABC.345/01DE-35", &TipoDocumento::Cnpj.padrao_mascara()), "**.ABC.345/01DE-**");
+}
+
+#[test]
+fn test_redigir() {
+    let texto = "Cliente 255.248.930-33 pagou com 5312 8338 4531 6765; CNPJ 76553412000110, tel (11) 98765-4321.";
+    assert_eq!(
+        redigir(texto),
+        "Cliente ***.248.930-** pagou com **** **** **** 6765; CNPJ **5534120001**, tel (11) 98765-4321."
+    );
+
+    // Números formatados com o dígito verificador incorreto também são ocultados
+    assert_eq!(redigir("CPF 255.248.930-34"), "CPF ***.248.930-**");
+
+    let redator = Redator::new()
+        .tipos(&[TipoDocumento::Cpf])
+        .padrao(TipoDocumento::Cpf, PadraoMascara::Total);
+    assert_eq!(
+        redator.redigir("CPF 255.248.930-33, cartão 5312 8338 4531 6765"),
+        "CPF ***.***.***-**, cartão 5312 8338 4531 6765"
+    );
+}
@@ -0,0 +1,88 @@
+use std::collections::{BTreeSet, HashMap};
+use std::thread;
+
+use validador_br::erro::ErroValidacao;
+use validador_br::formatar::Formatar;
+use validador_br::owned::{CartaoCreditoBuf, CnpjBuf, CnsBuf, CodigoBarrasGs1Buf, CpfBuf, IeBuf,
+    PisBuf, RenavamBuf,
+};
+use validador_br::types::{Cpf, Uf};
+use validador_br::validador::Validador;
+
+#[test]
+fn test_cpf_buf() {
+    let cpf = CpfBuf::try_from("255.248.930-33").unwrap();
+    assert_eq!(cpf.as_str(), "25524893033");
+    assert_eq!(cpf.digitos(), [2, 5, 5, 2, 4, 8, 9, 3, 0, 3, 3]);
+    assert_eq!(cpf.to_string(), "255.248.930-33");
+    assert_eq!(format!("{cpf:?}"), "CpfBuf(\"25524893033\")");
+    assert!(cpf.validar());
+    assert!(cpf.as_documento().validar());
+
+    // Formatado ou não, o número normalizado é o mesmo
+    assert_eq!(cpf, "25524893033".parse().unwrap());
+    assert_eq!(cpf, CpfBuf::try_from(String::from("255.248.930-33")).unwrap());
+    assert_eq!(cpf, CpfBuf::try_from(Cpf("255 248 930 33")).unwrap());
+
+    assert_eq!(
+        CpfBuf::try_from("255.248.930-34"),
+        Err(ErroValidacao::DigitoVerificador {
+            esperado: vec![3, 3],
+            encontrado: vec![3, 4]
+        })
+    );
+    assert!("111.111.111-11".parse::<CpfBuf>().is_err());
+}
+
+#[test]
+fn test_owned_colecoes() {
+    let mut mapa = HashMap::new();
+    mapa.insert(CpfBuf::try_from("085.668.830-47").unwrap(), "Ana");
+    mapa.insert(CpfBuf::try_from("712.926.512-45").unwrap(), "Bruno");
+    assert_eq!(mapa.get(&"08566883047".parse::<CpfBuf>().unwrap()), Some(&"Ana"));
+
+    let ordenados: BTreeSet<CnpjBuf> = ["76.553.412/0001-10", "45.184.338/0001-89", "14.572.457.0001-85"]
+        .iter()
+        .map(|numero| numero.parse().unwrap())
+        .collect();
+    let ordenados: Vec<&str> = ordenados.iter().map(|cnpj| cnpj.as_str()).collect();
+    assert_eq!(ordenados, vec!["14572457000185", "45184338000189", "76553412000110"]);
+}
+
+#[test]
+fn test_cnpj_buf_alfanumerico() {
+    let cnpj: CnpjBuf = "12.abc.345/01de-35".parse().unwrap();
+    assert_eq!(cnpj.as_str(), "12ABC34501DE35");
+    assert_eq!(cnpj.to_string(), "12.ABC.345/01DE-35");
+    assert_eq!(cnpj.digitos(), [1, 2, 17, 18, 19, 3, 4, 5, 0, 1, 20, 21, 3, 5]);
+    assert_eq!(cnpj, CnpjBuf::try_from("12ABC34501DE35").unwrap());
+}
+
+#[test]
+fn test_owned_thread() {
+    let corpo = String::from("{\"cpf\": \"974.749.266-01\"}");
+    let cpf: CpfBuf = corpo[9..23].parse().unwrap();
+    drop(corpo);
+    let resultado = thread::spawn(move || cpf.formatar()).join().unwrap();
+    assert_eq!(resultado, "974.749.266-01");
+}
+
+#[test]
+fn test_owned_tipos() {
+    assert_eq!(PisBuf::try_from("608.37951.54-6").unwrap().to_string(), "608.37951.54-6");
+    assert_eq!(CnsBuf::try_from("184184462180018").unwrap().to_string(), "184 1844 6218 0018");
+    assert_eq!(RenavamBuf::try_from("891353364").unwrap().as_str(), "00891353364");
+    assert_eq!(
+        RenavamBuf::try_from("891353364").unwrap(),
+        RenavamBuf::try_from("00891353364").unwrap()
+    );
+    assert_eq!(CartaoCreditoBuf::try_from("3479 467653 71543").unwrap().as_str(), "347946765371543");
+    assert_eq!(CodigoBarrasGs1Buf::try_from("7891234-2").unwrap().as_str(), "78912342");
+
+    let ie = IeBuf::try_from("SP 110.042.490.114").unwrap();
+    assert_eq!(ie.uf(), Uf::SP);
+    assert_eq!(ie.as_str(), "110042490114");
+    assert_eq!(ie.to_string(), "SP 110.042.490.114");
+    assert_eq!(ie, IeBuf::try_from((Uf::SP, "110042490114")).unwrap());
+    assert!(IeBuf::try_from((Uf::MG, "110042490114")).is_err());
+}
@@ -0,0 +1,91 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::formatar::Formatar;
+use validador_br::types::{ChavePix, TipoChavePix};
+use validador_br::validador::Validador;
+
+fn chave(texto: &str) -> (TipoChavePix, String) {
+    let chave = ChavePix::try_from(texto).unwrap();
+    (chave.tipo(), chave.to_string())
+}
+
+#[test]
+fn test_detectar() {
+    assert_eq!(chave("255.248.930-33"), (TipoChavePix::Cpf, "25524893033".to_string()));
+    assert_eq!(chave("76.553.412/0001-10"), (TipoChavePix::Cnpj, "76553412000110".to_string()));
+    assert_eq!(chave("12.abc.345/01de-35"), (TipoChavePix::Cnpj, "12ABC34501DE35".to_string()));
+    assert_eq!(chave("Maria.Silva@Exemplo.COM.br"), (TipoChavePix::Email, "maria.silva@exemplo.com.br".to_string()));
+    assert_eq!(chave("+55 (61) 99876-5432"), (TipoChavePix::Telefone, "+5561998765432".to_string()));
+    assert_eq!(chave("+5521987654321"), (TipoChavePix::Telefone, "+5521987654321".to_string()));
+    assert_eq!(
+        chave("9F8B3C2A-1D4E-4F6A-8B7C-0D1E2F3A4B5C"),
+        (TipoChavePix::Aleatoria, "9f8b3c2a-1d4e-4f6a-8b7c-0d1e2f3a4b5c".to_string())
+    );
+}
+
+#[test]
+fn test_tipo_informado() {
+    // sem o +55 o telefone só é aceito quando o tipo é informado
+    assert!(ChavePix::try_from("(11) 98765-4321").is_err());
+    let telefone = ChavePix::try_from((TipoChavePix::Telefone, "(11) 98765-4321")).unwrap();
+    assert_eq!(telefone.as_str(), "+5511987654321");
+    assert_eq!(telefone.formatar(), "+55 (11) 98765-4321");
+
+    // 11 dígitos de um celular não são um CPF válido
+    assert!(ChavePix::try_from((TipoChavePix::Cpf, "11987654321")).is_err());
+    assert_eq!("PHONE".parse::<TipoChavePix>(), Ok(TipoChavePix::Telefone));
+    assert_eq!("aleatoria".parse::<TipoChavePix>(), Ok(TipoChavePix::Aleatoria));
+    assert_eq!(TipoChavePix::Aleatoria.to_string(), "EVP");
+}
+
+#[test]
+fn test_chave_invalida() {
+    let campo = |campo: &'static str, encontrado: &str| {
+        Err(ErroValidacao::CampoInvalido {
+            campo,
+            encontrado: encontrado.to_string(),
+        })
+    };
+    assert!(matches!(
+        ChavePix::try_from("255.248.930-34"),
+        Err(ErroValidacao::DigitoVerificador { .. })
+    ));
+    assert_eq!(ChavePix::try_from("fulano@exemplo"), campo("e-mail", "fulano@exemplo"));
+    assert_eq!(ChavePix::try_from("fulano@@exemplo.com"), campo("e-mail", "fulano@@exemplo.com"));
+    assert_eq!(ChavePix::try_from(".fulano@exemplo.com"), campo("e-mail", ".fulano@exemplo.com"));
+    assert_eq!(ChavePix::try_from("fulano@-exemplo.com"), campo("e-mail", "fulano@-exemplo.com"));
+    let longo = format!("{}@exemplo.com.br", "a".repeat(64));
+    assert_eq!(ChavePix::try_from(longo.as_str()), campo("e-mail", &longo));
+
+    // outro país, DDD com zero, fixo e quantidade de dígitos
+    assert_eq!(ChavePix::try_from("+1 415 555 2671"), campo("telefone", "+1 415 555 2671"));
+    assert_eq!(ChavePix::try_from("+5501987654321"), campo("telefone", "+5501987654321"));
+    assert_eq!(ChavePix::try_from("+551133334444"), campo("telefone", "+551133334444"));
+    assert_eq!(ChavePix::try_from("+55119876543210"), campo("telefone", "+55119876543210"));
+
+    // versão 1 e caractere não hexadecimal
+    assert_eq!(
+        ChavePix::try_from("9f8b3c2a-1d4e-1f6a-8b7c-0d1e2f3a4b5c"),
+        campo("chave aleatória", "9f8b3c2a-1d4e-1f6a-8b7c-0d1e2f3a4b5c")
+    );
+    assert_eq!(
+        ChavePix::try_from("9f8b3c2a-1d4e-4f6a-8b7c-0d1e2f3a4b5g"),
+        campo("chave aleatória", "9f8b3c2a-1d4e-4f6a-8b7c-0d1e2f3a4b5g")
+    );
+
+    assert_eq!(
+        ChavePix::try_from("qualquer coisa"),
+        Err(ErroValidacao::TipoInvalido { encontrado: "qualquer coisa".to_string() })
+    );
+    assert!(!ChavePix::is_valid(""));
+}
+
+#[test]
+fn test_validador() {
+    for texto in ["255.248.930-33", "76553412000110", "a@b.co", "+5511987654321", "9f8b3c2a-1d4e-4f6a-8b7c-0d1e2f3a4b5c"] {
+        assert!(ChavePix::is_valid(texto), "{texto}");
+        let chave: ChavePix = texto.parse().unwrap();
+        assert!(chave.validar());
+        // a forma normalizada é aceita novamente com o mesmo tipo
+        assert_eq!(ChavePix::try_from(chave.as_str()), Ok(chave.clone()));
+    }
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "pseudonimizacao")]
+
+use validador_br::erro::ErroValidacao;
+use validador_br::pseudonimizacao::Pseudonimizador;
+use validador_br::types::{Documento, TipoDocumento};
+use validador_br::validador::Validador;
+
+#[test]
+fn test_pseudonimo() {
+    let pseudonimizador = Pseudonimizador::new(b"chave secreta");
+    // HMAC-SHA256("chave secreta", "cpf:25524893033")
+    let esperado = "56469bc9d59f046a50a23ca661f0b8532907ca436000a0be5830a659ba184e40";
+    assert_eq!(pseudonimizador.pseudonimo_de(TipoDocumento::Cpf, "255.248.930-33").unwrap(), esperado);
+    assert_eq!(pseudonimizador.pseudonimo_de(TipoDocumento::Cpf, "25524893033").unwrap(), esperado);
+
+    let documento: Documento = "cpf:255 248 930 33".parse().unwrap();
+    assert_eq!(pseudonimizador.pseudonimo(&documento), esperado);
+
+    // A chave e o tipo alteram o pseudônimo
+    assert_ne!(Pseudonimizador::new(b"outra chave").pseudonimo(&documento), esperado);
+    assert_ne!(
+        pseudonimizador.pseudonimo_de(TipoDocumento::Pis, "25524893034"),
+        pseudonimizador.pseudonimo_de(TipoDocumento::Renavam, "25524893034")
+    );
+
+    assert_eq!(
+        pseudonimizador.pseudonimo_de(TipoDocumento::Cpf, "255.248.930-34"),
+        Err(ErroValidacao::DigitoVerificador {
+            esperado: vec![3, 3],
+            encontrado: vec![3, 4],
+        })
+    );
+    assert_eq!(format!("{pseudonimizador:?}"), "Pseudonimizador { .. }");
+}
+
+#[test]
+fn test_token() {
+    let pseudonimizador = Pseudonimizador::new("chave secreta");
+    let documentos = [
+        "cpf:255.248.930-33",
+        "cnpj:76.553.412/0001-10",
+        "pis:608.37951.54-6",
+        "cns:184 1844 6218 0018",
+        "titulo:0043 5687 0906",
+        "cnh:81814756744",
+        "renavam:639884962",
+        "rg:14.176.381-4",
+    ];
+    for texto in documentos {
+        let documento: Documento = texto.parse().unwrap();
+        let token = pseudonimizador.token(&documento).unwrap();
+        assert_eq!(token.tipo(), documento.tipo());
+        assert_ne!(token, documento);
+        assert!(token.validar(), "{token}");
+        assert!(Documento::is_valid(&token.to_string()), "{token}");
+        // Determinístico
+        assert_eq!(pseudonimizador.token(&documento).unwrap(), token);
+        assert_ne!(Pseudonimizador::new("outra").token(&documento).unwrap(), token);
+    }
+
+    let cartao: Documento = "cartao:5312833845316765".parse().unwrap();
+    assert_eq!(pseudonimizador.token(&cartao), Err(ErroValidacao::NaoImplementado));
+}
@@ -0,0 +1,39 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::owned::CpfBuf;
+use validador_br::types::{Cpf, RegiaoFiscal, Uf};
+
+#[test]
+fn test_regiao_fiscal() {
+    assert_eq!(Cpf("255.248.930-33").regiao_fiscal(), Ok(RegiaoFiscal::Decima));
+    assert_eq!(Cpf("123.456.788-10").regiao_fiscal(), Ok(RegiaoFiscal::Oitava));
+    assert_eq!(Cpf("974.749.266-01").regiao_fiscal(), Ok(RegiaoFiscal::Sexta));
+    assert_eq!(CpfBuf::try_from("123.456.788-10").unwrap().regiao_fiscal(), RegiaoFiscal::Oitava);
+
+    assert_eq!(RegiaoFiscal::Oitava.ufs(), &[Uf::SP]);
+    assert_eq!(RegiaoFiscal::Primeira.ufs(), &[Uf::DF, Uf::GO, Uf::MS, Uf::MT, Uf::TO]);
+    assert_eq!(RegiaoFiscal::Decima.to_string(), "10ª Região Fiscal");
+
+    // O CPF é validado antes
+    assert!(matches!(
+        Cpf("085.668.830-48").regiao_fiscal(),
+        Err(ErroValidacao::DigitoVerificador { .. })
+    ));
+}
+
+#[test]
+fn test_regiao_fiscal_ufs() {
+    // Cada UF pertence a exatamente uma região
+    for uf in Uf::TODAS {
+        let regioes: Vec<RegiaoFiscal> = RegiaoFiscal::TODAS
+            .into_iter()
+            .filter(|regiao| regiao.ufs().contains(&uf))
+            .collect();
+        assert_eq!(regioes, vec![RegiaoFiscal::de_uf(uf)]);
+    }
+
+    for regiao in RegiaoFiscal::TODAS {
+        assert_eq!(RegiaoFiscal::from_digito(regiao.digito()), Some(regiao));
+    }
+    assert_eq!(RegiaoFiscal::from_digito(0), Some(RegiaoFiscal::Decima));
+    assert_eq!(RegiaoFiscal::from_digito(10), None);
+}
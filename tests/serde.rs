@@ -0,0 +1,65 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use validador_br::erro::ErroValidacao;
+use validador_br::owned::{CnpjBuf, CpfBuf, IeBuf, RenavamBuf};
+use validador_br::types::{ChavePix, Cpf, Uf};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Cliente {
+    cpf: CpfBuf,
+    #[serde(with = "validador_br::serializacao::formatado")]
+    cnpj: CnpjBuf,
+    #[serde(with = "validador_br::serializacao::canonico")]
+    renavam: RenavamBuf,
+    ie: IeBuf,
+}
+
+#[test]
+fn test_serde_owned() {
+    let json = r#"{"cpf": "255.248.930-33", "cnpj": "76553412000110", "renavam": "639884962", "ie": "SP 110.042.490.114"}"#;
+    let cliente: Cliente = serde_json::from_str(json).unwrap();
+    assert_eq!(cliente.cpf.as_str(), "25524893033");
+    assert_eq!(cliente.ie.uf(), Uf::SP);
+    assert_eq!(
+        serde_json::to_string(&cliente).unwrap(),
+        r#"{"cpf":"25524893033","cnpj":"76.553.412/0001-10","renavam":"00639884962","ie":"SP 110042490114"}"#
+    );
+
+    // O resultado da serialização pode ser lido novamente
+    let json = serde_json::to_string(&cliente).unwrap();
+    let lido: Cliente = serde_json::from_str(&json).unwrap();
+    assert_eq!(lido.cpf, cliente.cpf);
+    assert_eq!(lido.cnpj, cliente.cnpj);
+    assert_eq!(lido.ie, cliente.ie);
+}
+
+#[test]
+fn test_serde_invalido() {
+    let erro = serde_json::from_str::<CpfBuf>(r#""255.248.930-34""#).unwrap_err();
+    let esperado = ErroValidacao::DigitoVerificador {
+        esperado: vec![3, 3],
+        encontrado: vec![3, 4],
+    };
+    assert!(erro.to_string().starts_with(&esperado.to_string()));
+
+    assert!(serde_json::from_str::<CnpjBuf>(r#""11111111111111""#).is_err());
+    assert!(serde_json::from_str::<IeBuf>(r#""110.042.490.114""#).is_err());
+    assert!(serde_json::from_str::<CpfBuf>("25524893033").is_err());
+}
+
+#[test]
+fn test_serde_emprestado() {
+    let json = r#"["255.248.930-33"]"#;
+    let lista: Vec<Cpf> = serde_json::from_str(json).unwrap();
+    assert_eq!(lista[0].0, "255.248.930-33");
+    assert_eq!(serde_json::to_string(&lista).unwrap(), r#"["25524893033"]"#);
+    assert!(serde_json::from_str::<Vec<Cpf>>(r#"["255.248.930-34"]"#).is_err());
+}
+
+#[test]
+fn test_chave_pix() {
+    let chave: ChavePix = serde_json::from_str(r#""Fulano@Exemplo.com""#).unwrap();
+    assert_eq!(serde_json::to_string(&chave).unwrap(), r#""fulano@exemplo.com""#);
+    assert!(serde_json::from_str::<ChavePix>(r#""fulano@exemplo""#).is_err());
+}
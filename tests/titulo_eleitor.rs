@@ -0,0 +1,48 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::gerador_dv::GeradorDv;
+use validador_br::owned::TituloEleitorBuf;
+use validador_br::types::{TituloEleitor, Uf, UfTitulo};
+use validador_br::validador::Validador;
+
+#[test]
+fn test_titulo_uf() {
+    assert_eq!(TituloEleitor("0043 5687 0906").uf(), Ok(UfTitulo::Uf(Uf::SC)));
+    assert_eq!(TituloEleitor("781613810175").uf(), Ok(UfTitulo::Uf(Uf::SP)));
+    assert_eq!(TituloEleitor("272.20357 20-20").uf(), Ok(UfTitulo::Uf(Uf::DF)));
+    assert_eq!(TituloEleitorBuf::try_from("8645582519 29").unwrap().uf(), UfTitulo::Uf(Uf::MS));
+
+    let exterior = TituloEleitor::completar("1234567828").unwrap();
+    assert_eq!(TituloEleitor(&exterior).uf(), Ok(UfTitulo::Exterior));
+    assert_eq!(UfTitulo::Exterior.to_string(), "ZZ");
+
+    for codigo in 1..=28 {
+        assert_eq!(UfTitulo::from_codigo(codigo).unwrap().codigo(), codigo);
+    }
+    assert_eq!(UfTitulo::from_codigo(0), None);
+    assert_eq!(UfTitulo::from_codigo(29), None);
+}
+
+#[test]
+fn test_titulo_uf_invalida() {
+    assert_eq!(
+        TituloEleitor::verificar("1234 5678 2900"),
+        Err(ErroValidacao::UfInvalida { encontrado: "29".to_string() })
+    );
+    assert_eq!(
+        TituloEleitor::gerar_dv("12345678 00"),
+        Err(ErroValidacao::UfInvalida { encontrado: "00".to_string() })
+    );
+    assert!(TituloEleitor("1234 5678 9900").uf().is_err());
+}
+
+#[test]
+fn test_titulo_sp_mg() {
+    // A soma do número sequencial 12121213 é múltipla de 11: resto 0
+    assert_eq!(TituloEleitor::gerar_dv("12121213 01"), Ok(vec![1, 6]));
+    assert_eq!(TituloEleitor::gerar_dv("12121213 02"), Ok(vec![1, 3]));
+    assert_eq!(TituloEleitor::gerar_dv("12121213 05"), Ok(vec![0, 7]));
+    assert!(TituloEleitor::is_valid("1212 1213 0116"));
+    assert!(TituloEleitor::is_valid("1212 1213 0213"));
+    assert!(TituloEleitor::is_valid("1212 1213 0507"));
+    assert!(!TituloEleitor::is_valid("1212 1213 0106"));
+}
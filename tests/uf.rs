@@ -0,0 +1,59 @@
+use validador_br::erro::ErroValidacao;
+use validador_br::types::{Regiao, Uf};
+
+#[test]
+fn test_sigla() {
+    for uf in Uf::TODAS {
+        assert_eq!(Uf::from_sigla(uf.sigla()), Some(uf));
+        assert_eq!(uf.to_string(), uf.sigla());
+    }
+    assert_eq!(Uf::from_sigla("rs"), Some(Uf::RS));
+    assert_eq!(Uf::from_sigla("XX"), None);
+    assert_eq!(Uf::RS.to_string(), "RS");
+}
+
+#[test]
+fn test_from_str() {
+    assert_eq!("SP".parse::<Uf>(), Ok(Uf::SP));
+    assert_eq!("sp".parse::<Uf>(), Ok(Uf::SP));
+    assert_eq!("São Paulo".parse::<Uf>(), Ok(Uf::SP));
+    assert_eq!("sao paulo".parse::<Uf>(), Ok(Uf::SP));
+    assert_eq!("Mato Grosso do Sul".parse::<Uf>(), Ok(Uf::MS));
+    assert_eq!("Mato Grosso".parse::<Uf>(), Ok(Uf::MT));
+    assert_eq!("Espírito Santo".parse::<Uf>(), Ok(Uf::ES));
+    assert!(matches!("XX".parse::<Uf>(), Err(ErroValidacao::UfInvalida { .. })));
+}
+
+#[test]
+fn test_codigo_ibge() {
+    assert_eq!(Uf::SP.codigo_ibge(), 35);
+    assert_eq!(Uf::DF.codigo_ibge(), 53);
+    assert_eq!(Uf::from_codigo_ibge(31), Some(Uf::MG));
+    assert_eq!(Uf::from_codigo_ibge(30), None);
+    for uf in Uf::TODAS {
+        assert_eq!(Uf::from_codigo_ibge(uf.codigo_ibge()), Some(uf));
+    }
+    assert_eq!(Uf::SP.nome(), "São Paulo");
+}
+
+#[test]
+fn test_regiao() {
+    assert_eq!(Uf::AM.regiao(), Regiao::Norte);
+    assert_eq!(Uf::BA.regiao(), Regiao::Nordeste);
+    assert_eq!(Uf::GO.regiao(), Regiao::CentroOeste);
+    assert_eq!(Uf::DF.regiao(), Regiao::CentroOeste);
+    assert_eq!(Regiao::Sul.ufs(), vec![Uf::PR, Uf::RS, Uf::SC]);
+    assert_eq!(Regiao::CentroOeste.to_string(), "Centro-Oeste");
+
+    let total: usize = [
+        Regiao::Norte,
+        Regiao::Nordeste,
+        Regiao::CentroOeste,
+        Regiao::Sudeste,
+        Regiao::Sul,
+    ]
+    .iter()
+    .map(|regiao| regiao.ufs().len())
+    .sum();
+    assert_eq!(total, 27);
+}